@@ -0,0 +1,274 @@
+//! # mini_matrix
+//!
+//! Block construction: concatenation, block-diagonal matrices and splitting.
+//!
+//! Stable Rust cannot spell shapes such as `M x {N + K}` in a signature, so the shape of every
+//! result is a type parameter, usually inferred from the binding, and shapes that do not add up
+//! are rejected at compile time.
+
+use num::Num;
+
+use crate::{Matrix, Vector};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Clone,
+{
+    /// Concatenates the matrix horizontally with an `M x K` matrix, giving `[A | B]`.
+    ///
+    /// The result must be `M x S` with `S = N + K`:
+    ///
+    /// ```compile_fail
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b: Matrix<i32, 2, 3> = a.hstack(&a);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5], [6]]);
+    /// let ab: Matrix<i32, 2, 3> = a.hstack(&b);
+    /// assert_eq!(ab.store, [[1, 2, 5], [3, 4, 6]]);
+    /// ```
+    pub fn hstack<const K: usize, const S: usize>(
+        &self,
+        other: &Matrix<T, M, K>,
+    ) -> Matrix<T, M, S> {
+        const {
+            assert!(
+                S == N + K,
+                "hstack of M x N and M x K matrices is M x (N + K)"
+            )
+        };
+
+        Matrix {
+            store: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    if j < N {
+                        self[(i, j)].clone()
+                    } else {
+                        other[(i, j - N)].clone()
+                    }
+                })
+            }),
+        }
+    }
+
+    /// Concatenates the matrix vertically with a `P x N` matrix, stacking `B` below `A`.
+    ///
+    /// The result must be `R x N` with `R = M + P`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2, 3]]);
+    /// let b = Matrix::from([[4, 5, 6], [7, 8, 9]]);
+    /// let ab: Matrix<i32, 3, 3> = a.vstack(&b);
+    /// assert_eq!(ab.store, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// ```
+    pub fn vstack<const P: usize, const R: usize>(
+        &self,
+        other: &Matrix<T, P, N>,
+    ) -> Matrix<T, R, N> {
+        const {
+            assert!(
+                R == M + P,
+                "vstack of M x N and P x N matrices is (M + P) x N"
+            )
+        };
+
+        Matrix {
+            store: std::array::from_fn(|i| {
+                if i < M {
+                    self.store[i].clone()
+                } else {
+                    other.store[i - M].clone()
+                }
+            }),
+        }
+    }
+
+    /// Appends a vector as a new last column, giving the augmented matrix `[A | b]`.
+    ///
+    /// The result must be `M x S` with `S = N + 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// // Solve x + 2y = 5, 3x + 4y = 6 by reducing the augmented matrix.
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// let b = Vector::from([5.0, 6.0]);
+    /// let augmented: Matrix<f64, 2, 3> = a.append_column(&b);
+    /// assert_eq!(augmented.row_echelon().store, [[1.0, 0.0, -4.0], [0.0, 1.0, 4.5]]);
+    /// ```
+    pub fn append_column<const S: usize>(&self, column: &Vector<T, M>) -> Matrix<T, M, S> {
+        const {
+            assert!(
+                S == N + 1,
+                "appending a column to an M x N matrix gives M x (N + 1)"
+            )
+        };
+
+        Matrix {
+            store: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    if j < N {
+                        self[(i, j)].clone()
+                    } else {
+                        column[i].clone()
+                    }
+                })
+            }),
+        }
+    }
+
+    /// Appends a vector as a new last row.
+    ///
+    /// The result must be `R x N` with `R = M + 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let ab: Matrix<i32, 3, 2> = a.append_row(&Vector::from([5, 6]));
+    /// assert_eq!(ab.store, [[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    pub fn append_row<const R: usize>(&self, row: &Vector<T, N>) -> Matrix<T, R, N> {
+        const {
+            assert!(
+                R == M + 1,
+                "appending a row to an M x N matrix gives (M + 1) x N"
+            )
+        };
+
+        Matrix {
+            store: std::array::from_fn(|i| {
+                if i < M {
+                    self.store[i].clone()
+                } else {
+                    std::array::from_fn(|j| row[j].clone())
+                }
+            }),
+        }
+    }
+
+    /// Splits the matrix into its first `K` columns and its remaining `L` columns, undoing
+    /// [`Matrix::hstack`].
+    ///
+    /// `K + L` must equal `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let ab = Matrix::from([[1, 2, 5], [3, 4, 6]]);
+    /// let (a, b): (Matrix<i32, 2, 2>, Matrix<i32, 2, 1>) = ab.split_columns();
+    /// assert_eq!(a.store, [[1, 2], [3, 4]]);
+    /// assert_eq!(b.store, [[5], [6]]);
+    /// ```
+    pub fn split_columns<const K: usize, const L: usize>(
+        &self,
+    ) -> (Matrix<T, M, K>, Matrix<T, M, L>) {
+        const {
+            assert!(
+                K + L == N,
+                "splitting N columns into K and L needs K + L = N"
+            )
+        };
+
+        (
+            Matrix {
+                store: std::array::from_fn(|i| std::array::from_fn(|j| self[(i, j)].clone())),
+            },
+            Matrix {
+                store: std::array::from_fn(|i| std::array::from_fn(|j| self[(i, K + j)].clone())),
+            },
+        )
+    }
+
+    /// Splits the matrix into its first `P` rows and its remaining `Q` rows, undoing
+    /// [`Matrix::vstack`].
+    ///
+    /// `P + Q` must equal `M`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let ab = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let (a, b): (Matrix<i32, 1, 3>, Matrix<i32, 2, 3>) = ab.split_rows();
+    /// assert_eq!(a.store, [[1, 2, 3]]);
+    /// assert_eq!(b.store, [[4, 5, 6], [7, 8, 9]]);
+    /// ```
+    pub fn split_rows<const P: usize, const Q: usize>(&self) -> (Matrix<T, P, N>, Matrix<T, Q, N>) {
+        const { assert!(P + Q == M, "splitting M rows into P and Q needs P + Q = M") };
+
+        (
+            Matrix {
+                store: std::array::from_fn(|i| self.store[i].clone()),
+            },
+            Matrix {
+                store: std::array::from_fn(|i| self.store[P + i].clone()),
+            },
+        )
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Clone + Num,
+{
+    /// Builds the block-diagonal matrix `[A 0; 0 B]` from the matrix and a `P x Q` matrix.
+    ///
+    /// The blocks need not be square; the result must be `R x S` with `R = M + P` and
+    /// `S = N + Q`, and everything outside the two blocks is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5]]);
+    /// let ab: Matrix<i32, 3, 3> = a.block_diagonal(&b);
+    /// assert_eq!(ab.store, [[1, 2, 0], [3, 4, 0], [0, 0, 5]]);
+    /// ```
+    pub fn block_diagonal<const P: usize, const Q: usize, const R: usize, const S: usize>(
+        &self,
+        other: &Matrix<T, P, Q>,
+    ) -> Matrix<T, R, S> {
+        const {
+            assert!(
+                R == M + P && S == N + Q,
+                "block diagonal of M x N and P x Q matrices is (M + P) x (N + Q)"
+            )
+        };
+
+        Matrix {
+            store: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    if i < M && j < N {
+                        self[(i, j)].clone()
+                    } else if i >= M && j >= N {
+                        other[(i - M, j - N)].clone()
+                    } else {
+                        T::zero()
+                    }
+                })
+            }),
+        }
+    }
+}
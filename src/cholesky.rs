@@ -0,0 +1,323 @@
+//! # mini_matrix
+//!
+//! Cholesky and LDLᵀ factorizations of symmetric matrices.
+
+use num::Float;
+
+use crate::{LinalgError, Matrix, Vector};
+
+/// The Cholesky factorization `A = L * Lᵀ` of a symmetric positive-definite matrix.
+///
+/// `L` is lower triangular with a positive diagonal. The factorization needs half the work of an
+/// LU decomposition and is numerically stable without pivoting.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{Matrix, Vector};
+///
+/// let a = Matrix::<f64, 2, 2>::from([[4.0, 2.0], [2.0, 3.0]]);
+/// let cholesky = a.cholesky().unwrap();
+/// assert_eq!(cholesky.l().store, [[2.0, 0.0], [1.0, 2.0_f64.sqrt()]]);
+///
+/// let x = cholesky.solve(&Vector::from([6.0, 5.0]));
+/// assert!((x[0] - 1.0).abs() < 1e-12);
+/// assert!((x[1] - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    l: Matrix<T, N, N>,
+}
+
+/// The factorization `P * A * Pᵀ = L * D * Lᵀ` of a symmetric, possibly indefinite matrix.
+///
+/// `L` is unit lower triangular, `D` is block diagonal with `1 x 1` and `2 x 2` blocks, and `P` is
+/// the symmetric permutation chosen by Bunch-Kaufman pivoting. Unlike the Cholesky
+/// factorization it exists for every symmetric matrix, including semi-definite and indefinite
+/// ones.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{Matrix, Vector};
+///
+/// // Indefinite: no Cholesky factorization exists, and the first pivot is zero.
+/// let a = Matrix::<f64, 2, 2>::from([[0.0, 1.0], [1.0, 0.0]]);
+/// assert!(a.cholesky().is_err());
+///
+/// let x = a.ldlt().unwrap().solve(&Vector::from([2.0, 3.0])).unwrap();
+/// assert!((x[0] - 3.0).abs() < 1e-12);
+/// assert!((x[1] - 2.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ldlt<T, const N: usize> {
+    l: Matrix<T, N, N>,
+    d: Matrix<T, N, N>,
+    permutation: [usize; N],
+    tolerance: T,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default,
+{
+    /// Computes the Cholesky factorization of a symmetric positive-definite matrix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Cholesky)` on success, [`LinalgError::NotSymmetric`] if the matrix is not
+    /// symmetric within [`Matrix::default_tolerance`], or [`LinalgError::NotPositiveDefinite`]
+    /// if a non-positive pivot is met during the factorization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 1.0]]);
+    /// assert_eq!(a.cholesky(), Err(LinalgError::NotPositiveDefinite));
+    /// ```
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, LinalgError> {
+        if !self.is_symmetric_within(self.default_tolerance()) {
+            return Err(LinalgError::NotSymmetric);
+        }
+
+        let mut l = Matrix::zero();
+        for j in 0..N {
+            let diagonal = (0..j).fold(self[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+            if diagonal.is_nan() || diagonal <= T::zero() {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            l[(j, j)] = diagonal.sqrt();
+            for i in j + 1..N {
+                let sum = (0..j).fold(self[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = sum / l[(j, j)];
+            }
+        }
+        Ok(Cholesky { l })
+    }
+
+    /// Computes the `LDLᵀ` factorization of a symmetric matrix with Bunch-Kaufman pivoting.
+    ///
+    /// At every step either a `1 x 1` pivot or a `2 x 2` pivot block is chosen, depending on how
+    /// the diagonal entry compares to the largest off-diagonal entry of its column, which bounds
+    /// the growth of the entries of `L`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Ldlt)` on success, or [`LinalgError::NotSymmetric`] if the matrix is not
+    /// symmetric within [`Matrix::default_tolerance`]. Singular matrices factor successfully and
+    /// are reported by [`Ldlt::solve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 3, 3>::from([[1.0, 2.0, 0.0], [2.0, 1.0, 0.0], [0.0, 0.0, -3.0]]);
+    /// let ldlt = a.ldlt().unwrap();
+    /// let d = ldlt.d();
+    /// assert!(d[(0, 1)] == d[(1, 0)]);
+    /// ```
+    pub fn ldlt(&self) -> Result<Ldlt<T, N>, LinalgError> {
+        let tolerance = self.default_tolerance();
+        if !self.is_symmetric_within(tolerance) {
+            return Err(LinalgError::NotSymmetric);
+        }
+
+        // Bunch-Kaufman threshold (1 + √17) / 8, which minimises the element growth bound.
+        let alpha = (T::one() + T::from(17.0).unwrap().sqrt()) / T::from(8.0).unwrap();
+        let mut a = *self;
+        let mut l = Matrix::identity();
+        let mut d = Matrix::zero();
+        let mut permutation: [usize; N] = std::array::from_fn(|i| i);
+
+        let mut k = 0;
+        while k < N {
+            let diagonal = a[(k, k)].abs();
+            let (imax, column_max) = (k + 1..N).fold((k, T::zero()), |(best, max), i| {
+                if a[(i, k)].abs() > max {
+                    (i, a[(i, k)].abs())
+                } else {
+                    (best, max)
+                }
+            });
+
+            if diagonal.max(column_max) <= tolerance {
+                // The whole remaining column is zero: D gets a zero pivot and L a zero column.
+                k += 1;
+                continue;
+            }
+
+            let (pivot, step) = if diagonal >= alpha * column_max {
+                (k, 1)
+            } else {
+                let row_max = (k..N)
+                    .filter(|&j| j != imax)
+                    .fold(T::zero(), |max, j| max.max(a[(imax, j)].abs()));
+                if diagonal * row_max >= alpha * column_max * column_max {
+                    (k, 1)
+                } else if a[(imax, imax)].abs() >= alpha * row_max {
+                    (imax, 1)
+                } else {
+                    (imax, 2)
+                }
+            };
+
+            let target = k + step - 1;
+            if pivot != target {
+                a.store.swap(pivot, target);
+                for row in a.store.iter_mut() {
+                    row.swap(pivot, target);
+                }
+                for j in 0..k {
+                    let held = l[(pivot, j)];
+                    l[(pivot, j)] = l[(target, j)];
+                    l[(target, j)] = held;
+                }
+                permutation.swap(pivot, target);
+            }
+
+            if step == 1 {
+                let pivot = a[(k, k)];
+                d[(k, k)] = pivot;
+                for i in k + 1..N {
+                    l[(i, k)] = a[(i, k)] / pivot;
+                }
+                for i in k + 1..N {
+                    for j in k + 1..N {
+                        a[(i, j)] = a[(i, j)] - l[(i, k)] * a[(k, j)];
+                    }
+                }
+            } else {
+                let (d11, d21, d22) = (a[(k, k)], a[(k + 1, k)], a[(k + 1, k + 1)]);
+                let det = d11 * d22 - d21 * d21;
+                d[(k, k)] = d11;
+                d[(k + 1, k)] = d21;
+                d[(k, k + 1)] = d21;
+                d[(k + 1, k + 1)] = d22;
+                for i in k + 2..N {
+                    l[(i, k)] = (a[(i, k)] * d22 - a[(i, k + 1)] * d21) / det;
+                    l[(i, k + 1)] = (a[(i, k + 1)] * d11 - a[(i, k)] * d21) / det;
+                }
+                for i in k + 2..N {
+                    for j in k + 2..N {
+                        a[(i, j)] =
+                            a[(i, j)] - l[(i, k)] * a[(k, j)] - l[(i, k + 1)] * a[(k + 1, j)];
+                    }
+                }
+            }
+            k += step;
+        }
+
+        Ok(Ldlt {
+            l,
+            d,
+            permutation,
+            tolerance,
+        })
+    }
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+    T: Float + Default,
+{
+    /// Returns the lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T, N, N> {
+        self.l
+    }
+
+    /// Solves `A * x = b` by forward substitution with `L` and back substitution with `Lᵀ`.
+    pub fn solve(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let mut x = *b;
+        for i in 0..N {
+            let sum = (0..i).fold(x[i], |acc, j| acc - self.l[(i, j)] * x[j]);
+            x[i] = sum / self.l[(i, i)];
+        }
+        for i in (0..N).rev() {
+            let sum = (i + 1..N).fold(x[i], |acc, j| acc - self.l[(j, i)] * x[j]);
+            x[i] = sum / self.l[(i, i)];
+        }
+        x
+    }
+}
+
+impl<T, const N: usize> Ldlt<T, N>
+where
+    T: Float + Default,
+{
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T, N, N> {
+        self.l
+    }
+
+    /// Returns the block diagonal factor `D`.
+    pub fn d(&self) -> Matrix<T, N, N> {
+        self.d
+    }
+
+    /// Returns the symmetric permutation: row `i` of `P * A * Pᵀ` comes from row
+    /// `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    /// Returns the permutation matrix `P` such that `P * A * Pᵀ = L * D * Lᵀ`.
+    pub fn p(&self) -> Matrix<T, N, N> {
+        let mut p = Matrix::zero();
+        for (i, &row) in self.permutation.iter().enumerate() {
+            p[(i, row)] = T::one();
+        }
+        p
+    }
+
+    /// Solves `A * x = b` using the stored factorization.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vector)` with the solution, or [`LinalgError::Singular`] if a block of `D` is
+    /// singular within [`Matrix::default_tolerance`] of the factored matrix.
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        let mut x = Vector::<T, N>::zero();
+        for i in 0..N {
+            x[i] = b[self.permutation[i]];
+        }
+        for i in 0..N {
+            x[i] = (0..i).fold(x[i], |acc, j| acc - self.l[(i, j)] * x[j]);
+        }
+
+        let mut k = 0;
+        while k < N {
+            if k + 1 < N && self.d[(k + 1, k)] != T::zero() {
+                let (d11, d21, d22) = (self.d[(k, k)], self.d[(k + 1, k)], self.d[(k + 1, k + 1)]);
+                let det = d11 * d22 - d21 * d21;
+                // |det| / ‖block‖∞ bounds the smaller eigenvalue of the block from below, so this
+                // rejects every block with an eigenvalue within the tolerance, like a 1x1 pivot.
+                let norm = d11.abs().max(d22.abs()) + d21.abs();
+                if det.abs() <= self.tolerance * norm {
+                    return Err(LinalgError::Singular);
+                }
+                let (x1, x2) = (x[k], x[k + 1]);
+                x[k] = (x1 * d22 - x2 * d21) / det;
+                x[k + 1] = (x2 * d11 - x1 * d21) / det;
+                k += 2;
+            } else {
+                if self.d[(k, k)].abs() <= self.tolerance {
+                    return Err(LinalgError::Singular);
+                }
+                x[k] = x[k] / self.d[(k, k)];
+                k += 1;
+            }
+        }
+
+        for i in (0..N).rev() {
+            x[i] = (i + 1..N).fold(x[i], |acc, j| acc - self.l[(j, i)] * x[j]);
+        }
+        let mut solution = Vector::zero();
+        for i in 0..N {
+            solution[self.permutation[i]] = x[i];
+        }
+        Ok(solution)
+    }
+}
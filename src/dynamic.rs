@@ -0,0 +1,995 @@
+//! # mini_matrix
+//!
+//! Heap-allocated matrices and vectors whose dimensions are chosen at runtime.
+
+use num::{Float, Num};
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::elimination;
+use crate::{LinalgError, Matrix, Vector};
+
+/// A matrix whose number of rows and columns is only known at runtime.
+///
+/// `DMatrix` offers the operations of [`Matrix`] for data whose shape comes from a file or from
+/// user input. Shapes that the compiler checks for `Matrix` are checked at runtime instead: the
+/// named methods return [`LinalgError::DimensionMismatch`] or [`LinalgError::NotSquare`], and
+/// the operators panic. Elements are stored contiguously in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{DMatrix, Matrix};
+///
+/// let a = DMatrix::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+/// assert_eq!(a.size(), (2, 3));
+/// assert_eq!(a[(1, 2)], 6);
+///
+/// let fixed: Matrix<i32, 2, 3> = a.try_into().unwrap();
+/// assert_eq!(fixed.store, [[1, 2, 3], [4, 5, 6]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DMatrix<T> {
+    rows: usize,
+    cols: usize,
+    store: Vec<T>,
+}
+
+/// A vector whose length is only known at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{DVector, Vector};
+///
+/// let v = DVector::from(vec![1.0, 2.0, 2.0]);
+/// assert_eq!(v.len(), 3);
+/// assert_eq!(v.norm(), 3.0);
+///
+/// let fixed: Vector<f64, 3> = v.try_into().unwrap();
+/// assert_eq!(fixed.store, [1.0, 2.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DVector<T> {
+    pub store: Vec<T>,
+}
+
+/* ************************************************* */
+/*                      DMatrix                      */
+/* ************************************************* */
+
+impl<T> DMatrix<T>
+where
+    T: Copy + Default,
+{
+    /// Creates a new `DMatrix` with `rows` rows and `cols` columns from elements in row-major
+    /// order.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DMatrix)`, or [`LinalgError::DimensionMismatch`] if `data` does not hold
+    /// exactly `rows * cols` elements. The error compares the flat data as column vectors:
+    /// `expected` is `(rows * cols, 1)` and `found` is `(data.len(), 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{DMatrix, LinalgError};
+    ///
+    /// let a = DMatrix::new(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(a[(1, 0)], 3);
+    /// assert_eq!(
+    ///     DMatrix::new(2, 2, vec![1, 2, 3]),
+    ///     Err(LinalgError::DimensionMismatch { expected: (4, 1), found: (3, 1) })
+    /// );
+    /// ```
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, LinalgError> {
+        if data.len() != rows * cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (rows * cols, 1),
+                found: (data.len(), 1),
+            });
+        }
+        Ok(Self {
+            rows,
+            cols,
+            store: data,
+        })
+    }
+
+    /// Creates a new `DMatrix` from a vector of rows.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DMatrix)`, or [`LinalgError::DimensionMismatch`] if the rows do not all have
+    /// the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{DMatrix, LinalgError};
+    ///
+    /// let ragged = DMatrix::from_vecs(vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(
+    ///     ragged,
+    ///     Err(LinalgError::DimensionMismatch { expected: (2, 2), found: (2, 1) })
+    /// );
+    /// ```
+    pub fn from_vecs(vecs: Vec<Vec<T>>) -> Result<Self, LinalgError> {
+        let rows = vecs.len();
+        let cols = vecs.first().map_or(0, Vec::len);
+        let mut store = Vec::with_capacity(rows * cols);
+        for vec in vecs.iter() {
+            if vec.len() != cols {
+                return Err(LinalgError::DimensionMismatch {
+                    expected: (rows, cols),
+                    found: (rows, vec.len()),
+                });
+            }
+            store.extend_from_slice(vec);
+        }
+        Ok(Self { rows, cols, store })
+    }
+
+    /// Creates a `rows x cols` matrix with all elements set to the default value of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let a = DMatrix::<f64>::zero(2, 3);
+    /// assert_eq!(a.size(), (2, 3));
+    /// assert_eq!(a[(1, 2)], 0.0);
+    /// ```
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            store: vec![T::default(); rows * cols],
+        }
+    }
+
+    /// Returns the dimensions of the matrix as a tuple `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns `true` if the matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.store
+    }
+
+    /// Returns row `i` as a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.store[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the transpose of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let t = a.transpose();
+    /// assert_eq!(t.size(), (3, 2));
+    /// assert_eq!(t.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::zero(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    fn require_square(&self) -> Result<(), LinalgError> {
+        if self.is_square() {
+            Ok(())
+        } else {
+            Err(LinalgError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            })
+        }
+    }
+
+    fn require_same_size(&self, other: &Self) -> Result<(), LinalgError> {
+        if self.size() == other.size() {
+            Ok(())
+        } else {
+            Err(LinalgError::DimensionMismatch {
+                expected: self.size(),
+                found: other.size(),
+            })
+        }
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Copy + Default + Num,
+{
+    /// Creates the `n x n` identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let i = DMatrix::<i32>::identity(2);
+    /// assert_eq!(i.as_slice(), &[1, 0, 0, 1]);
+    /// ```
+    pub fn identity(n: usize) -> Self {
+        let mut result = Self::zero(n, n);
+        for i in 0..n {
+            result[(i, i)] = T::one();
+        }
+        result
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    /// Adds another matrix to this matrix in-place.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())`, or [`LinalgError::DimensionMismatch`] if the shapes differ, in which
+    /// case the matrix is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let mut a = DMatrix::new(1, 2, vec![1, 2]).unwrap();
+    /// a.add(&DMatrix::new(1, 2, vec![10, 20]).unwrap()).unwrap();
+    /// assert_eq!(a.as_slice(), &[11, 22]);
+    /// assert!(a.add(&DMatrix::zero(2, 1)).is_err());
+    /// ```
+    pub fn add(&mut self, other: &Self) -> Result<(), LinalgError> {
+        self.require_same_size(other)?;
+        for (l, r) in self.store.iter_mut().zip(other.store.iter()) {
+            *l += *r;
+        }
+        Ok(())
+    }
+
+    /// Subtracts another matrix from this matrix in-place.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())`, or [`LinalgError::DimensionMismatch`] if the shapes differ, in which
+    /// case the matrix is left unchanged.
+    pub fn sub(&mut self, other: &Self) -> Result<(), LinalgError> {
+        self.require_same_size(other)?;
+        for (l, r) in self.store.iter_mut().zip(other.store.iter()) {
+            *l -= *r;
+        }
+        Ok(())
+    }
+
+    /// Multiplies this matrix by a scalar value in-place.
+    pub fn scl(&mut self, scalar: T) {
+        for elem in self.store.iter_mut() {
+            *elem *= scalar;
+        }
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Num + Copy + AddAssign + Default,
+{
+    /// Multiplies the matrix by a vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DVector)` with one entry per row, or [`LinalgError::DimensionMismatch`] if the
+    /// length of the vector differs from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{DMatrix, DVector};
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let v = DVector::from(vec![1, 0, -1]);
+    /// assert_eq!(a.mul_vec(&v).unwrap().store, vec![-2, -2]);
+    /// assert!(a.mul_vec(&DVector::from(vec![1, 2])).is_err());
+    /// ```
+    pub fn mul_vec(&self, vec: &DVector<T>) -> Result<DVector<T>, LinalgError> {
+        if vec.len() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (vec.len(), 1),
+            });
+        }
+        let mut result = DVector::zero(self.rows);
+        for i in 0..self.rows {
+            for (e1, e2) in self.row(i).iter().zip(vec.iter()) {
+                result[i] += *e1 * *e2;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Multiplies the matrix by another matrix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DMatrix)` of shape `(self.rows, mat.cols)`, or
+    /// [`LinalgError::DimensionMismatch`] if `mat` does not have as many rows as `self` has
+    /// columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![1, 2, 3]]).unwrap();
+    /// let b = DMatrix::from_vecs(vec![vec![1, 0], vec![0, 1], vec![1, 1]]).unwrap();
+    /// assert_eq!(a.mul_mat(&b).unwrap().as_slice(), &[4, 5]);
+    /// assert!(b.mul_mat(&b).is_err());
+    /// ```
+    pub fn mul_mat(&self, mat: &DMatrix<T>) -> Result<DMatrix<T>, LinalgError> {
+        if mat.rows != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, mat.cols),
+                found: mat.size(),
+            });
+        }
+        let mut result = DMatrix::zero(self.rows, mat.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let e1 = self[(i, k)];
+                for j in 0..mat.cols {
+                    result[(i, j)] += e1 * mat[(k, j)];
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Calculates the trace of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(T)`, or [`LinalgError::NotSquare`] for a non-square matrix.
+    pub fn trace(&self) -> Result<T, LinalgError> {
+        self.require_square()?;
+        let mut trace = T::zero();
+        for i in 0..self.rows {
+            trace += self[(i, i)];
+        }
+        Ok(trace)
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Clone + Num,
+{
+    /// Converts the matrix to its Reduced Row-Echelon Form (RREF).
+    ///
+    /// This is the runtime-sized counterpart of [`Matrix::row_echelon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![2.0, 4.0], vec![1.0, 3.0]]).unwrap();
+    /// assert_eq!(a.row_echelon().as_slice(), &[1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    pub fn row_echelon(&self) -> DMatrix<T> {
+        let mut result = self.clone();
+        elimination::row_echelon(&mut result.store, self.rows, self.cols);
+        result
+    }
+
+    /// Calculates the rank of the matrix by counting the non-zero rows of its row echelon form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![1, 2, 3], vec![2, 4, 6]]).unwrap();
+    /// assert_eq!(a.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize {
+        elimination::non_zero_rows(&self.row_echelon().store, self.rows, self.cols)
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Copy + Default + Num + PartialOrd + Neg<Output = T>,
+{
+    /// Computes the determinant of a square matrix by fraction-free Gaussian elimination
+    /// (Bareiss) with partial pivoting, exactly as [`Matrix::determinant`] does.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(T)`, or [`LinalgError::NotSquare`] for a non-square matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{DMatrix, LinalgError};
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(a.determinant(), Ok(-2));
+    ///
+    /// let b = DMatrix::<i32>::zero(2, 3);
+    /// assert_eq!(b.determinant(), Err(LinalgError::NotSquare { rows: 2, cols: 3 }));
+    /// ```
+    pub fn determinant(&self) -> Result<T, LinalgError> {
+        self.require_square()?;
+        let mut a = self.clone();
+        Ok(elimination::determinant(&mut a.store, self.rows))
+    }
+}
+
+impl<T> DMatrix<T>
+where
+    T: Float + Default,
+{
+    /// Returns the tolerance `max(rows, cols) * ε * max|aᵢⱼ|` used to decide whether a pivot is
+    /// numerically zero, as [`Matrix::default_tolerance`] does.
+    pub fn default_tolerance(&self) -> T {
+        let largest = self.store.iter().fold(T::zero(), |acc, x| acc.max(x.abs()));
+        T::from(self.rows.max(self.cols)).unwrap() * T::epsilon() * largest
+    }
+
+    /// Calculates the inverse of a square matrix by Gauss-Jordan elimination with partial
+    /// pivoting, treating pivots up to [`DMatrix::default_tolerance`] as zero.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DMatrix)`, [`LinalgError::NotSquare`] for a non-square matrix, or
+    /// [`LinalgError::Singular`] if the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{DMatrix, LinalgError};
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![2.0, 0.0], vec![0.0, 4.0]]).unwrap();
+    /// assert_eq!(a.inverse().unwrap().as_slice(), &[0.5, 0.0, 0.0, 0.25]);
+    ///
+    /// let singular = DMatrix::from_vecs(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    /// assert_eq!(singular.inverse(), Err(LinalgError::Singular));
+    /// ```
+    pub fn inverse(&self) -> Result<Self, LinalgError> {
+        self.inverse_with_tolerance(self.default_tolerance())
+    }
+
+    /// Calculates the inverse of a square matrix, treating any pivot whose magnitude is at most
+    /// `tolerance` as zero.
+    pub fn inverse_with_tolerance(&self, tolerance: T) -> Result<Self, LinalgError> {
+        self.require_square()?;
+        let mut a = self.clone();
+        let mut inv = Self::identity(self.rows);
+        elimination::invert(&mut a.store, &mut inv.store, self.rows, tolerance)?;
+        Ok(inv)
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    /// Returns the element at `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        &self.store[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    /// Returns a mutable reference to the element at `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        &mut self.store[i * self.cols + j]
+    }
+}
+
+impl<T> Add for DMatrix<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    type Output = Self;
+
+    /// Adds two matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes differ; use [`DMatrix::add`] for a fallible version.
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        if let Err(error) = DMatrix::add(&mut result, &rhs) {
+            panic!("{}", error);
+        }
+        result
+    }
+}
+
+impl<T> Sub for DMatrix<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    type Output = Self;
+
+    /// Subtracts two matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes differ; use [`DMatrix::sub`] for a fallible version.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        if let Err(error) = DMatrix::sub(&mut result, &rhs) {
+            panic!("{}", error);
+        }
+        result
+    }
+}
+
+impl<T> Mul<T> for DMatrix<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    type Output = Self;
+
+    /// Multiplies a matrix by a scalar value.
+    fn mul(self, rhs: T) -> Self::Output {
+        let mut result = self;
+        result.scl(rhs);
+        result
+    }
+}
+
+impl<T> Mul<DVector<T>> for DMatrix<T>
+where
+    T: Num + Copy + AddAssign + Default,
+{
+    type Output = DVector<T>;
+
+    /// Multiplies a matrix by a vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the vector differs from the number of columns; use
+    /// [`DMatrix::mul_vec`] for a fallible version.
+    fn mul(self, rhs: DVector<T>) -> Self::Output {
+        self.mul_vec(&rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> Mul for DMatrix<T>
+where
+    T: Num + Copy + AddAssign + Default,
+{
+    type Output = Self;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner dimensions differ; use [`DMatrix::mul_mat`] for a fallible version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DMatrix;
+    ///
+    /// let a = DMatrix::from_vecs(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let b = DMatrix::from_vecs(vec![vec![5, 6], vec![7, 8]]).unwrap();
+    /// assert_eq!((a * b).as_slice(), &[19, 22, 43, 50]);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat(&rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<T> Neg for DMatrix<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Negates all elements of the matrix.
+    fn neg(self) -> Self::Output {
+        let mut result = self;
+        for elem in result.store.iter_mut() {
+            *elem = -*elem;
+        }
+        result
+    }
+}
+
+impl<T> Display for DMatrix<T>
+where
+    T: Display,
+{
+    /// Formats the matrix for display, one row per line, like [`Matrix`].
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for i in 0..self.rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "// [")?;
+            for j in 0..self.cols {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{:.1}", self.store[i * self.cols + j])?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f)?;
+        Ok(())
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for DMatrix<T>
+where
+    T: Clone,
+{
+    /// Moves a fixed-size matrix into a `DMatrix`.
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        Self {
+            rows: M,
+            cols: N,
+            store: matrix.store.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<DMatrix<T>> for Matrix<T, M, N>
+where
+    T: Copy + Default,
+{
+    type Error = LinalgError;
+
+    /// Copies a `DMatrix` into a fixed-size matrix.
+    ///
+    /// Fails with [`LinalgError::DimensionMismatch`] unless the shape is exactly `M x N`.
+    fn try_from(matrix: DMatrix<T>) -> Result<Self, Self::Error> {
+        if matrix.size() != (M, N) {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (M, N),
+                found: matrix.size(),
+            });
+        }
+        let mut result = Matrix::zero();
+        for (i, row) in result.store.iter_mut().enumerate() {
+            row.copy_from_slice(matrix.row(i));
+        }
+        Ok(result)
+    }
+}
+
+/* ************************************************* */
+/*                      DVector                      */
+/* ************************************************* */
+
+impl<T> DVector<T>
+where
+    T: Copy + Default,
+{
+    /// Creates a vector of length `n` with all elements set to the default value of type `T`.
+    pub fn zero(n: usize) -> Self {
+        Self {
+            store: vec![T::default(); n],
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn size(&self) -> usize {
+        self.store.len()
+    }
+
+    fn require_same_size(&self, other: &Self) -> Result<(), LinalgError> {
+        if self.len() == other.len() {
+            Ok(())
+        } else {
+            Err(LinalgError::DimensionMismatch {
+                expected: (self.len(), 1),
+                found: (other.len(), 1),
+            })
+        }
+    }
+}
+
+impl<T> DVector<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    /// Adds another vector to this vector in-place.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())`, or [`LinalgError::DimensionMismatch`] if the lengths differ, in which
+    /// case the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::DVector;
+    ///
+    /// let mut v = DVector::from(vec![1, 2]);
+    /// v.add(&DVector::from(vec![3, 4])).unwrap();
+    /// assert_eq!(v.store, vec![4, 6]);
+    /// assert!(v.add(&DVector::from(vec![1])).is_err());
+    /// ```
+    pub fn add(&mut self, rhs: &Self) -> Result<(), LinalgError> {
+        self.require_same_size(rhs)?;
+        for (l, r) in self.store.iter_mut().zip(rhs.store.iter()) {
+            *l += *r;
+        }
+        Ok(())
+    }
+
+    /// Subtracts another vector from this vector in-place.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())`, or [`LinalgError::DimensionMismatch`] if the lengths differ, in which
+    /// case the vector is left unchanged.
+    pub fn sub(&mut self, rhs: &Self) -> Result<(), LinalgError> {
+        self.require_same_size(rhs)?;
+        for (l, r) in self.store.iter_mut().zip(rhs.store.iter()) {
+            *l -= *r;
+        }
+        Ok(())
+    }
+
+    /// Multiplies this vector by a scalar value in-place.
+    pub fn scl(&mut self, scalar: T) {
+        for elem in self.store.iter_mut() {
+            *elem *= scalar;
+        }
+    }
+}
+
+impl<T> DVector<T>
+where
+    T: Num + Sum + Copy + Default,
+{
+    /// Computes the dot product of two vectors.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(T)`, or [`LinalgError::DimensionMismatch`] if the lengths differ.
+    pub fn dot(&self, v: &Self) -> Result<T, LinalgError> {
+        self.require_same_size(v)?;
+        Ok(self
+            .store
+            .iter()
+            .zip(v.store.iter())
+            .map(|(a, b)| *a * *b)
+            .sum())
+    }
+}
+
+impl<T> DVector<T>
+where
+    T: Float + Sum<T>,
+{
+    /// Calculates the L1 norm (Manhattan norm) of the vector.
+    pub fn norm_1(&self) -> T {
+        self.store.iter().map(|x| x.abs()).sum()
+    }
+
+    /// Calculates the L2 norm (Euclidean norm) of the vector.
+    pub fn norm(&self) -> T {
+        self.store.iter().map(|x| *x * *x).sum::<T>().sqrt()
+    }
+
+    /// Calculates the L-infinity norm (maximum norm) of the vector.
+    pub fn norm_inf(&self) -> T {
+        self.store
+            .iter()
+            .map(|x| x.abs())
+            .fold(T::zero(), |a, b| a.max(b))
+    }
+}
+
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.store[index]
+    }
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.store[index]
+    }
+}
+
+impl<T> Deref for DVector<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.store
+    }
+}
+
+impl<T> DerefMut for DVector<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.store
+    }
+}
+
+impl<T> Add for DVector<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    type Output = Self;
+
+    /// Adds two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ; use [`DVector::add`] for a fallible version.
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        if let Err(error) = DVector::add(&mut result, &rhs) {
+            panic!("{}", error);
+        }
+        result
+    }
+}
+
+impl<T> Sub for DVector<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    type Output = Self;
+
+    /// Subtracts two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths differ; use [`DVector::sub`] for a fallible version.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        if let Err(error) = DVector::sub(&mut result, &rhs) {
+            panic!("{}", error);
+        }
+        result
+    }
+}
+
+impl<T> Mul<T> for DVector<T>
+where
+    T: AddAssign + SubAssign + MulAssign + Copy + Default,
+{
+    type Output = Self;
+
+    /// Multiplies a vector by a scalar value.
+    fn mul(self, scalar: T) -> Self::Output {
+        let mut result = self;
+        result.scl(scalar);
+        result
+    }
+}
+
+impl<T> Neg for DVector<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Negates all elements of the vector.
+    fn neg(self) -> Self::Output {
+        let mut result = self;
+        for elem in result.store.iter_mut() {
+            *elem = -*elem;
+        }
+        result
+    }
+}
+
+impl<T> Display for DVector<T>
+where
+    T: Display,
+{
+    /// Formats the vector for display, like [`Vector`].
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "//-> [")?;
+        for (i, val) in self.store.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:.1}", val)?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+impl<T> From<Vec<T>> for DVector<T> {
+    /// Wraps a `Vec` as a `DVector`.
+    fn from(data: Vec<T>) -> Self {
+        Self { store: data }
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for DVector<T>
+where
+    T: Copy,
+{
+    /// Copies a fixed-size vector into a `DVector`.
+    fn from(vector: Vector<T, N>) -> Self {
+        Self {
+            store: vector.store.to_vec(),
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<DVector<T>> for Vector<T, N>
+where
+    T: Copy + Default,
+{
+    type Error = LinalgError;
+
+    /// Copies a `DVector` into a fixed-size vector.
+    ///
+    /// Fails with [`LinalgError::DimensionMismatch`] unless the length is exactly `N`.
+    fn try_from(vector: DVector<T>) -> Result<Self, Self::Error> {
+        if vector.len() != N {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (N, 1),
+                found: (vector.len(), 1),
+            });
+        }
+        let mut result = Vector::zero();
+        result.store.copy_from_slice(&vector);
+        Ok(result)
+    }
+}
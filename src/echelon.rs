@@ -0,0 +1,179 @@
+//! # mini_matrix
+//!
+//! Reduced row echelon form with partial pivoting and a tolerance for negligible pivots.
+
+use num::Float;
+
+use crate::Matrix;
+
+/// The reduced row echelon form of an `M x N` matrix, together with its pivot columns.
+///
+/// Produced by [`Matrix::rref`] and [`Matrix::rref_with_tolerance`]. Entries that elimination
+/// treated as zero are stored as exact zeros, so the form is clean even when the input carries
+/// rounding noise.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::Matrix;
+///
+/// let a = Matrix::<f64, 3, 4>::from([
+///     [1.0, 2.0, 1.0, 1.0],
+///     [2.0, 4.0, 0.0, 6.0],
+///     [1.0, 2.0, 2.0, -1.0],
+/// ]);
+/// let rref = a.rref();
+/// assert_eq!(rref.pivot_columns(), &[0, 2]);
+/// assert_eq!(rref.free_columns(), vec![1, 3]);
+/// assert_eq!(rref.rank(), 2);
+/// assert_eq!(
+///     rref.reduced().store,
+///     [[1.0, 2.0, 0.0, 3.0], [0.0, 0.0, 1.0, -2.0], [0.0, 0.0, 0.0, 0.0]]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowEchelon<T, const M: usize, const N: usize> {
+    reduced: Matrix<T, M, N>,
+    pivot_columns: Vec<usize>,
+}
+
+impl<T, const M: usize, const N: usize> RowEchelon<T, M, N>
+where
+    T: Float + Default,
+{
+    /// Returns the reduced row echelon form.
+    pub fn reduced(&self) -> Matrix<T, M, N> {
+        self.reduced
+    }
+
+    /// Returns the columns holding a pivot, in increasing order; pivot `k` is in row `k`.
+    pub fn pivot_columns(&self) -> &[usize] {
+        &self.pivot_columns
+    }
+
+    /// Returns the columns without a pivot, which correspond to the free variables of `A * x = 0`.
+    pub fn free_columns(&self) -> Vec<usize> {
+        (0..N).filter(|j| !self.pivot_columns.contains(j)).collect()
+    }
+
+    /// Returns the rank, the number of pivots.
+    pub fn rank(&self) -> usize {
+        self.pivot_columns.len()
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Default,
+{
+    /// Computes the reduced row echelon form, treating pivots up to [`Matrix::default_tolerance`]
+    /// as zero.
+    ///
+    /// Unlike [`Matrix::row_echelon`], which takes the first pivot that is not exactly zero, this
+    /// picks the largest-magnitude candidate in each column and ignores rounding residue, so it
+    /// is the variant to use for floating-point data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// // The third row is the second minus the first, up to rounding.
+    /// let a = Matrix::<f64, 3, 3>::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.3, 0.3, 0.3]]);
+    /// let rref = a.rref();
+    /// assert_eq!(rref.rank(), 2);
+    /// assert_eq!(rref.pivot_columns(), &[0, 1]);
+    /// ```
+    pub fn rref(&self) -> RowEchelon<T, M, N> {
+        self.rref_with_tolerance(self.default_tolerance())
+    }
+
+    /// Computes the reduced row echelon form, treating pivots of magnitude at most `tolerance`
+    /// as zero.
+    ///
+    /// Each column is reduced by Gauss-Jordan elimination with partial pivoting: the remaining
+    /// row with the largest entry in the column is swapped up, scaled so the pivot is one, and
+    /// used to clear the column in every other row. A column whose largest remaining entry does
+    /// not exceed `tolerance` gets no pivot, and those entries are set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 1.0], [1.0, 1.0 + 1e-9]]);
+    /// assert_eq!(a.rref_with_tolerance(1e-12).pivot_columns(), &[0, 1]);
+    /// assert_eq!(a.rref_with_tolerance(1e-6).pivot_columns(), &[0]);
+    /// ```
+    pub fn rref_with_tolerance(&self, tolerance: T) -> RowEchelon<T, M, N> {
+        let mut a = *self;
+        let mut pivot_columns = Vec::new();
+        let mut row = 0;
+
+        for col in 0..N {
+            if row == M {
+                break;
+            }
+            let pivot_row = (row + 1..M).fold(row, |best, i| {
+                if a[(i, col)].abs() > a[(best, col)].abs() {
+                    i
+                } else {
+                    best
+                }
+            });
+            if a[(pivot_row, col)].abs() <= tolerance {
+                for i in row..M {
+                    a[(i, col)] = T::zero();
+                }
+                continue;
+            }
+            a.store.swap(row, pivot_row);
+
+            let pivot = a[(row, col)];
+            for j in col + 1..N {
+                a[(row, j)] = a[(row, j)] / pivot;
+            }
+            a[(row, col)] = T::one();
+            for i in (0..M).filter(|&i| i != row) {
+                let factor = a[(i, col)];
+                if factor == T::zero() {
+                    continue;
+                }
+                for j in col + 1..N {
+                    a[(i, j)] = a[(i, j)] - factor * a[(row, j)];
+                }
+                a[(i, col)] = T::zero();
+            }
+            pivot_columns.push(col);
+            row += 1;
+        }
+
+        RowEchelon {
+            reduced: a,
+            pivot_columns,
+        }
+    }
+
+    /// Computes the rank as the number of pivots of [`Matrix::rref`].
+    ///
+    /// Unlike [`Matrix::rank`], which counts rows that are exactly zero, rounding residue does not
+    /// make a singular matrix look full rank. [`Matrix::rank_with_tolerance`] computes the rank
+    /// from singular values instead, which is more reliable but more expensive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 3, 3>::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+    /// assert_eq!(a.echelon_rank(), 2);
+    /// ```
+    pub fn echelon_rank(&self) -> usize {
+        self.rref().rank()
+    }
+
+    /// Computes the rank as the number of pivots of [`Matrix::rref_with_tolerance`].
+    pub fn echelon_rank_with_tolerance(&self, tolerance: T) -> usize {
+        self.rref_with_tolerance(tolerance).rank()
+    }
+}
@@ -0,0 +1,546 @@
+//! # mini_matrix
+//!
+//! Eigenvalue decompositions.
+
+use num::traits::float::TotalOrder;
+use num::{Complex, Float};
+
+use crate::{LinalgError, Matrix, Vector};
+
+/// Upper bound on the number of Jacobi sweeps; convergence is quadratic and typically takes fewer
+/// than ten sweeps.
+const MAX_SWEEPS: usize = 100;
+
+/// Upper bound on the number of QR iterations spent on a single eigenvalue, as in LAPACK.
+const MAX_ITERATIONS_PER_EIGENVALUE: usize = 30;
+
+/// The eigendecomposition `A = V * Λ * Vᵀ` of a symmetric matrix.
+///
+/// The eigenvalues are real and sorted in increasing order, and `V` is orthogonal with the
+/// eigenvector belonging to the `i`-th eigenvalue in column `i`.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::Matrix;
+///
+/// let a = Matrix::<f64, 2, 2>::from([[2.0, 1.0], [1.0, 2.0]]);
+/// let eigen = a.symmetric_eigen().unwrap();
+/// assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-12);
+/// assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-12);
+///
+/// let v = eigen.eigenvectors();
+/// assert!((v[(0, 1)].abs() - 0.5_f64.sqrt()).abs() < 1e-12);
+/// assert!((v[(0, 1)] - v[(1, 1)]).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymmetricEigen<T, const N: usize> {
+    eigenvalues: Vector<T, N>,
+    eigenvectors: Matrix<T, N, N>,
+}
+
+/// The real Schur decomposition `A = Q * T * Qᵀ` of a square matrix.
+///
+/// `Q` is orthogonal and `T` is quasi upper triangular: it is upper triangular except for `2 x 2`
+/// blocks on the diagonal, one for every pair of complex conjugate eigenvalues. The eigenvalues
+/// are listed in the order in which they appear on the diagonal of `T`, with the eigenvalue of
+/// positive imaginary part first within a conjugate pair.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::Matrix;
+///
+/// // A rotation by 90 degrees has eigenvalues ±i.
+/// let a = Matrix::<f64, 2, 2>::from([[0.0, -1.0], [1.0, 0.0]]);
+/// let schur = a.schur().unwrap();
+/// let eigenvalues = schur.eigenvalues();
+/// assert!(eigenvalues[0].re.abs() < 1e-12);
+/// assert!((eigenvalues[0].im - 1.0).abs() < 1e-12);
+/// assert_eq!(eigenvalues[1], eigenvalues[0].conj());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Schur<T, const N: usize> {
+    q: Matrix<T, N, N>,
+    t: Matrix<T, N, N>,
+    eigenvalues: Vector<Complex<T>, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default + TotalOrder,
+{
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix by cyclic Jacobi rotations.
+    ///
+    /// Every sweep annihilates each off-diagonal entry in turn with a plane rotation, until all of
+    /// them are negligible relative to the corresponding diagonal entries. The accumulated
+    /// rotations form the eigenvector matrix, which is therefore orthogonal to working precision
+    /// even for clustered eigenvalues.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(SymmetricEigen)` on success, [`LinalgError::NotSymmetric`] if the matrix is not
+    /// symmetric within [`Matrix::default_tolerance`], or [`LinalgError::NoConvergence`] if the
+    /// last of the 100 sweeps still had to rotate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [0.0, 1.0]]);
+    /// assert_eq!(a.symmetric_eigen(), Err(LinalgError::NotSymmetric));
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, N>, LinalgError> {
+        if !self.is_symmetric_within(self.default_tolerance()) {
+            return Err(LinalgError::NotSymmetric);
+        }
+
+        let two = T::one() + T::one();
+        let mut a = *self;
+        for i in 0..N {
+            for j in 0..i {
+                let mean = (self[(i, j)] + self[(j, i)]) / two;
+                a[(i, j)] = mean;
+                a[(j, i)] = mean;
+            }
+        }
+        let mut v = Matrix::<T, N, N>::identity();
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in p + 1..N {
+                    let apq = a[(p, q)];
+                    if apq == T::zero()
+                        || apq.abs() <= T::epsilon() * (a[(p, p)] * a[(q, q)]).abs().sqrt()
+                    {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let theta = (a[(q, q)] - a[(p, p)]) / (two * apq);
+                    let t = theta.signum() / (theta.abs() + (T::one() + theta * theta).sqrt());
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    for k in 0..N {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..N {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    a[(p, q)] = T::zero();
+                    a[(q, p)] = T::zero();
+
+                    for k in 0..N {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(LinalgError::NoConvergence);
+        }
+
+        let mut order: [usize; N] = std::array::from_fn(|i| i);
+        order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+
+        let mut eigenvalues = Vector::zero();
+        let mut eigenvectors = Matrix::<T, N, N>::zero();
+        for (k, &j) in order.iter().enumerate() {
+            eigenvalues[k] = a[(j, j)];
+            for i in 0..N {
+                eigenvectors[(i, k)] = v[(i, j)];
+            }
+        }
+        Ok(SymmetricEigen {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+
+    /// Computes the eigenvalues of a general square matrix, which may be complex.
+    ///
+    /// This is a shorthand for [`Matrix::schur`] that discards the Schur vectors.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vector)` with the eigenvalues in the order described on [`Schur`], or
+    /// [`LinalgError::NoConvergence`] if the QR iteration fails to converge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 3, 3>::from([[2.0, 0.0, 0.0], [0.0, 1.0, -2.0], [0.0, 2.0, 1.0]]);
+    /// let eigenvalues = a.eigenvalues().unwrap();
+    /// assert!(eigenvalues.iter().any(|z| (z.re - 2.0).abs() < 1e-12 && z.im == 0.0));
+    /// assert!(eigenvalues.iter().any(|z| (z.re - 1.0).abs() < 1e-12 && (z.im - 2.0).abs() < 1e-12));
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<T>, N>, LinalgError> {
+        self.schur().map(|schur| schur.eigenvalues)
+    }
+
+    /// Computes the real Schur decomposition of the matrix.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form by Householder similarity
+    /// transformations, and the Hessenberg matrix is then driven to quasi triangular form by
+    /// Francis double-shift QR iterations, which keep the arithmetic real even when the
+    /// eigenvalues are complex. Negligible subdiagonal entries are deflated as they appear, and
+    /// exceptional shifts are used to break the rare cycles of the standard shift strategy.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Schur)` on success, or [`LinalgError::NoConvergence`] if an eigenvalue is not
+    /// isolated within 30 iterations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 3, 3>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+    /// let schur = a.schur().unwrap();
+    /// let (q, t) = (schur.q(), schur.t());
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         let mut product = 0.0;
+    ///         for k in 0..3 {
+    ///             for l in 0..3 {
+    ///                 product += q[(i, k)] * t[(k, l)] * q[(j, l)];
+    ///             }
+    ///         }
+    ///         assert!((product - a[(i, j)]).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn schur(&self) -> Result<Schur<T, N>, LinalgError> {
+        let (mut h, mut vectors) = self.hessenberg();
+        let mut eigenvalues = Vector::<Complex<T>, N>::zero();
+
+        let two = T::one() + T::one();
+        let eps = T::epsilon();
+        let norm = (0..N)
+            .flat_map(|i| (i.saturating_sub(1)..N).map(move |j| (i, j)))
+            .fold(T::zero(), |acc, (i, j)| acc + h[(i, j)].abs());
+
+        let mut exshift = T::zero();
+        let mut iterations = 0;
+        let mut active = N;
+        while active > 0 {
+            let n = active - 1;
+
+            // Look for a single negligible subdiagonal entry.
+            let mut l = n;
+            while l > 0 {
+                let mut s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+                if s == T::zero() {
+                    s = norm;
+                }
+                if h[(l, l - 1)].abs() <= eps * s {
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == n {
+                // A single real root has deflated.
+                h[(n, n)] = h[(n, n)] + exshift;
+                eigenvalues[n] = Complex::new(h[(n, n)], T::zero());
+                if l > 0 {
+                    h[(n, n - 1)] = T::zero();
+                }
+                active -= 1;
+                iterations = 0;
+                continue;
+            }
+
+            if l + 1 == n {
+                // A 2 x 2 block has deflated: split it if its roots are real.
+                let w = h[(n, n - 1)] * h[(n - 1, n)];
+                let p = (h[(n - 1, n - 1)] - h[(n, n)]) / two;
+                let discriminant = p * p + w;
+                let root = discriminant.abs().sqrt();
+                h[(n, n)] = h[(n, n)] + exshift;
+                h[(n - 1, n - 1)] = h[(n - 1, n - 1)] + exshift;
+                let x = h[(n, n)];
+
+                if discriminant >= T::zero() {
+                    let z = if p >= T::zero() { p + root } else { p - root };
+                    let larger = x + z;
+                    let smaller = if z != T::zero() { x - w / z } else { larger };
+                    eigenvalues[n - 1] = Complex::new(larger, T::zero());
+                    eigenvalues[n] = Complex::new(smaller, T::zero());
+
+                    let sub = h[(n, n - 1)];
+                    let scale = sub.abs() + z.abs();
+                    let (p, q) = (sub / scale, z / scale);
+                    let r = (p * p + q * q).sqrt();
+                    let (p, q) = (p / r, q / r);
+                    for j in n - 1..N {
+                        let z = h[(n - 1, j)];
+                        h[(n - 1, j)] = q * z + p * h[(n, j)];
+                        h[(n, j)] = q * h[(n, j)] - p * z;
+                    }
+                    for i in 0..=n {
+                        let z = h[(i, n - 1)];
+                        h[(i, n - 1)] = q * z + p * h[(i, n)];
+                        h[(i, n)] = q * h[(i, n)] - p * z;
+                    }
+                    for i in 0..N {
+                        let z = vectors[(i, n - 1)];
+                        vectors[(i, n - 1)] = q * z + p * vectors[(i, n)];
+                        vectors[(i, n)] = q * vectors[(i, n)] - p * z;
+                    }
+                    h[(n, n - 1)] = T::zero();
+                } else {
+                    eigenvalues[n - 1] = Complex::new(x + p, root);
+                    eigenvalues[n] = Complex::new(x + p, -root);
+                }
+                if n >= 2 {
+                    h[(n - 1, n - 2)] = T::zero();
+                }
+                active -= 2;
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            if iterations > MAX_ITERATIONS_PER_EIGENVALUE {
+                return Err(LinalgError::NoConvergence);
+            }
+
+            // Form the shift from the trailing 2 x 2 block.
+            let mut x = h[(n, n)];
+            let mut y = h[(n - 1, n - 1)];
+            let mut w = h[(n, n - 1)] * h[(n - 1, n)];
+
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift, after Wilkinson.
+                exshift = exshift + x;
+                for i in 0..=n {
+                    h[(i, i)] = h[(i, i)] - x;
+                }
+                let s = h[(n, n - 1)].abs() + h[(n - 1, n - 2)].abs();
+                x = T::from(0.75).unwrap() * s;
+                y = x;
+                w = T::from(-0.4375).unwrap() * s * s;
+            }
+
+            // Look for two consecutive small subdiagonal entries, so that the bulge can be
+            // started further down.
+            let mut m = n - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = h[(m, m)];
+                r = x - z;
+                let s = y - z;
+                p = (r * s - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                q = h[(m + 1, m + 1)] - z - r - s;
+                r = h[(m + 2, m + 1)];
+                let s = p.abs() + q.abs() + r.abs();
+                p = p / s;
+                q = q / s;
+                r = r / s;
+                if m == l {
+                    break;
+                }
+                if h[(m, m - 1)].abs() * (q.abs() + r.abs())
+                    < eps
+                        * (p.abs() * (h[(m - 1, m - 1)].abs() + z.abs() + h[(m + 1, m + 1)].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=n {
+                h[(i, i - 2)] = T::zero();
+                if i > m + 2 {
+                    h[(i, i - 3)] = T::zero();
+                }
+            }
+
+            // Double-shift QR step on rows l..=n and columns m..=n, chasing the bulge down with
+            // 3 x 3 Householder reflectors.
+            for k in m..n {
+                let not_last = k + 1 != n;
+                let mut scale = T::zero();
+                if k != m {
+                    p = h[(k, k - 1)];
+                    q = h[(k + 1, k - 1)];
+                    r = if not_last {
+                        h[(k + 2, k - 1)]
+                    } else {
+                        T::zero()
+                    };
+                    scale = p.abs() + q.abs() + r.abs();
+                    if scale == T::zero() {
+                        continue;
+                    }
+                    p = p / scale;
+                    q = q / scale;
+                    r = r / scale;
+                }
+
+                let mut s = (p * p + q * q + r * r).sqrt();
+                if p < T::zero() {
+                    s = -s;
+                }
+                if s == T::zero() {
+                    continue;
+                }
+                if k != m {
+                    h[(k, k - 1)] = -s * scale;
+                } else if l != m {
+                    h[(k, k - 1)] = -h[(k, k - 1)];
+                }
+                p = p + s;
+                let (x, y, z) = (p / s, q / s, r / s);
+                q = q / p;
+                r = r / p;
+
+                for j in k..N {
+                    let mut p = h[(k, j)] + q * h[(k + 1, j)];
+                    if not_last {
+                        p = p + r * h[(k + 2, j)];
+                        h[(k + 2, j)] = h[(k + 2, j)] - p * z;
+                    }
+                    h[(k, j)] = h[(k, j)] - p * x;
+                    h[(k + 1, j)] = h[(k + 1, j)] - p * y;
+                }
+                for i in 0..=n.min(k + 3) {
+                    let mut p = x * h[(i, k)] + y * h[(i, k + 1)];
+                    if not_last {
+                        p = p + z * h[(i, k + 2)];
+                        h[(i, k + 2)] = h[(i, k + 2)] - p * r;
+                    }
+                    h[(i, k)] = h[(i, k)] - p;
+                    h[(i, k + 1)] = h[(i, k + 1)] - p * q;
+                }
+                for i in 0..N {
+                    let mut p = x * vectors[(i, k)] + y * vectors[(i, k + 1)];
+                    if not_last {
+                        p = p + z * vectors[(i, k + 2)];
+                        vectors[(i, k + 2)] = vectors[(i, k + 2)] - p * r;
+                    }
+                    vectors[(i, k)] = vectors[(i, k)] - p;
+                    vectors[(i, k + 1)] = vectors[(i, k + 1)] - p * q;
+                }
+            }
+        }
+
+        // The bulge chase leaves stale entries below the subdiagonal, which are zero in exact
+        // arithmetic and never read again.
+        for i in 2..N {
+            for j in 0..i - 1 {
+                h[(i, j)] = T::zero();
+            }
+        }
+
+        Ok(Schur {
+            q: vectors,
+            t: h,
+            eigenvalues,
+        })
+    }
+
+    /// Reduces the matrix to upper Hessenberg form `H = Qᵀ * A * Q` by Householder similarity
+    /// transformations, returning `(H, Q)`.
+    fn hessenberg(&self) -> (Matrix<T, N, N>, Matrix<T, N, N>) {
+        let mut h = *self;
+        let mut q = Matrix::<T, N, N>::identity();
+        let mut v = [T::zero(); N];
+
+        for m in 1..N.saturating_sub(1) {
+            let scale = (m..N).fold(T::zero(), |acc, i| acc + h[(i, m - 1)].abs());
+            if scale == T::zero() {
+                continue;
+            }
+            let mut length = T::zero();
+            for i in m..N {
+                v[i] = h[(i, m - 1)] / scale;
+                length = length + v[i] * v[i];
+            }
+            let mut g = length.sqrt();
+            if v[m] > T::zero() {
+                g = -g;
+            }
+            // With v = x - g e₁, the reflector is I - v vᵀ / weight.
+            let weight = length - v[m] * g;
+            v[m] = v[m] - g;
+
+            for j in 0..N {
+                let f = (m..N).fold(T::zero(), |acc, i| acc + v[i] * h[(i, j)]) / weight;
+                for i in m..N {
+                    h[(i, j)] = h[(i, j)] - f * v[i];
+                }
+            }
+            for i in 0..N {
+                let f = (m..N).fold(T::zero(), |acc, j| acc + v[j] * h[(i, j)]) / weight;
+                for j in m..N {
+                    h[(i, j)] = h[(i, j)] - f * v[j];
+                }
+            }
+            for i in 0..N {
+                let f = (m..N).fold(T::zero(), |acc, j| acc + v[j] * q[(i, j)]) / weight;
+                for j in m..N {
+                    q[(i, j)] = q[(i, j)] - f * v[j];
+                }
+            }
+            for i in m + 1..N {
+                h[(i, m - 1)] = T::zero();
+            }
+        }
+        (h, q)
+    }
+}
+
+impl<T, const N: usize> SymmetricEigen<T, N>
+where
+    T: Float + Default,
+{
+    /// Returns the eigenvalues in increasing order.
+    pub fn eigenvalues(&self) -> Vector<T, N> {
+        self.eigenvalues
+    }
+
+    /// Returns the orthogonal matrix whose `i`-th column is the unit eigenvector of the `i`-th
+    /// eigenvalue.
+    pub fn eigenvectors(&self) -> Matrix<T, N, N> {
+        self.eigenvectors
+    }
+}
+
+impl<T, const N: usize> Schur<T, N>
+where
+    T: Float + Default,
+{
+    /// Returns the orthogonal matrix `Q` of Schur vectors.
+    pub fn q(&self) -> Matrix<T, N, N> {
+        self.q
+    }
+
+    /// Returns the quasi upper triangular factor `T`.
+    pub fn t(&self) -> Matrix<T, N, N> {
+        self.t
+    }
+
+    /// Returns the eigenvalues in the order in which they appear on the diagonal of `T`.
+    pub fn eigenvalues(&self) -> Vector<Complex<T>, N> {
+        self.eigenvalues
+    }
+}
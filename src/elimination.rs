@@ -0,0 +1,228 @@
+//! # mini_matrix
+//!
+//! Gaussian elimination on row-major slices, shared by [`Matrix`](crate::Matrix) and
+//! [`DMatrix`](crate::DMatrix).
+
+use num::{CheckedDiv, CheckedMul, CheckedSub, Float, Num};
+use std::convert::Infallible;
+use std::ops::Neg;
+
+use crate::LinalgError;
+
+/// Swaps rows `i` and `k` of a row-major matrix with `cols` columns.
+fn swap_rows<T>(a: &mut [T], cols: usize, i: usize, k: usize) {
+    if i != k {
+        for j in 0..cols {
+            a.swap(i * cols + j, k * cols + j);
+        }
+    }
+}
+
+/// Reduces the `rows x cols` matrix `a` to reduced row-echelon form in place, treating only
+/// exact zeros as zero.
+pub(crate) fn row_echelon<T>(a: &mut [T], rows: usize, cols: usize)
+where
+    T: Clone + Num,
+{
+    let mut pivot = 0;
+
+    'outer: for r in 0..rows {
+        if cols <= pivot {
+            break;
+        }
+        let mut i = r;
+        while a[i * cols + pivot].is_zero() {
+            i += 1;
+            if i == rows {
+                i = r;
+                pivot += 1;
+                if cols == pivot {
+                    break 'outer;
+                }
+            }
+        }
+        swap_rows(a, cols, r, i);
+        let divisor = a[r * cols + pivot].clone();
+        for j in 0..cols {
+            a[r * cols + j] = a[r * cols + j].clone() / divisor.clone();
+        }
+        for j in 0..rows {
+            if j != r {
+                let hold = a[j * cols + pivot].clone();
+                for k in 0..cols {
+                    a[j * cols + k] =
+                        a[j * cols + k].clone() - hold.clone() * a[r * cols + k].clone();
+                }
+            }
+        }
+        pivot += 1;
+    }
+}
+
+/// Counts the non-zero rows of the `rows x cols` matrix `a`, which is the rank once `a` is in
+/// row-echelon form.
+pub(crate) fn non_zero_rows<T>(a: &[T], rows: usize, cols: usize) -> usize
+where
+    T: Num,
+{
+    (0..rows)
+        .filter(|&i| a[i * cols..(i + 1) * cols].iter().any(|x| !x.is_zero()))
+        .count()
+}
+
+/// Returns `-|x|`, which, unlike `|x|`, cannot overflow for two's complement integers, so
+/// comparing it orders entries by magnitude even when one of them is `T::min_value()`.
+fn negative_magnitude<T>(x: T) -> T
+where
+    T: Num + PartialOrd + Neg<Output = T>,
+{
+    if x > T::zero() {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Runs fraction-free Gaussian elimination (Bareiss) with partial pivoting on the `n x n` matrix
+/// `a`, overwriting it, and returns the determinant.
+///
+/// Every minor `(a_kk * a_ij - a_ik * a_kj) / previous` is computed by
+/// `minor(a_kk, a_ij, a_ik, a_kj, previous)` and the final sign change by `negate`, so the
+/// caller decides whether the arithmetic is checked; the first error either returns stops the
+/// elimination.
+fn bareiss<T, E>(
+    a: &mut [T],
+    n: usize,
+    minor: impl Fn(&T, &T, &T, &T, &T) -> Result<T, E>,
+    negate: impl Fn(T) -> Result<T, E>,
+) -> Result<T, E>
+where
+    T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+    let mut negated = false;
+    let mut previous = T::one();
+
+    for k in 0..n {
+        let pivot_row = (k + 1..n).fold(k, |best, i| {
+            if negative_magnitude(a[i * n + k].clone())
+                < negative_magnitude(a[best * n + k].clone())
+            {
+                i
+            } else {
+                best
+            }
+        });
+        if a[pivot_row * n + k].is_zero() {
+            return Ok(T::zero());
+        }
+        if pivot_row != k {
+            swap_rows(a, n, k, pivot_row);
+            negated = !negated;
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                a[i * n + j] = minor(
+                    &a[k * n + k],
+                    &a[i * n + j],
+                    &a[i * n + k],
+                    &a[k * n + j],
+                    &previous,
+                )?;
+            }
+        }
+        previous = a[k * n + k].clone();
+    }
+    if negated {
+        negate(previous)
+    } else {
+        Ok(previous)
+    }
+}
+
+/// Computes the determinant of the `n x n` matrix `a` by fraction-free Gaussian elimination
+/// (Bareiss) with partial pivoting, overwriting `a`.
+pub(crate) fn determinant<T>(a: &mut [T], n: usize) -> T
+where
+    T: Clone + Num + PartialOrd + Neg<Output = T>,
+{
+    let determinant = bareiss::<T, Infallible>(
+        a,
+        n,
+        |kk, ij, ik, kj, previous| {
+            Ok((kk.clone() * ij.clone() - ik.clone() * kj.clone()) / previous.clone())
+        },
+        |x| Ok(-x),
+    );
+    match determinant {
+        Ok(determinant) => determinant,
+        Err(never) => match never {},
+    }
+}
+
+/// Computes the determinant of the `n x n` matrix `a` like [`determinant`], but checks every
+/// product, difference and quotient, returning [`LinalgError::Overflow`] as soon as one does not
+/// fit in `T`.
+pub(crate) fn checked_determinant<T>(a: &mut [T], n: usize) -> Result<T, LinalgError>
+where
+    T: Clone + Num + PartialOrd + Neg<Output = T> + CheckedMul + CheckedSub + CheckedDiv,
+{
+    bareiss(
+        a,
+        n,
+        |kk, ij, ik, kj, previous| {
+            kk.checked_mul(ij)
+                .zip(ik.checked_mul(kj))
+                .and_then(|(kept, eliminated)| kept.checked_sub(&eliminated))
+                .and_then(|minor| minor.checked_div(previous))
+                .ok_or(LinalgError::Overflow)
+        },
+        |x| T::zero().checked_sub(&x).ok_or(LinalgError::Overflow),
+    )
+}
+
+/// Inverts the `n x n` matrix `a` by Gauss-Jordan elimination with partial pivoting, applying
+/// the same row operations to `inv`, which must hold the identity on entry. `a` is overwritten.
+///
+/// Returns [`LinalgError::Singular`] as soon as the best pivot is at most `tolerance` in
+/// magnitude.
+pub(crate) fn invert<T>(
+    a: &mut [T],
+    inv: &mut [T],
+    n: usize,
+    tolerance: T,
+) -> Result<(), LinalgError>
+where
+    T: Float,
+{
+    for k in 0..n {
+        let pivot_row = (k + 1..n).fold(k, |best, i| {
+            if a[i * n + k].abs() > a[best * n + k].abs() {
+                i
+            } else {
+                best
+            }
+        });
+        if a[pivot_row * n + k].abs() <= tolerance {
+            return Err(LinalgError::Singular);
+        }
+        swap_rows(a, n, k, pivot_row);
+        swap_rows(inv, n, k, pivot_row);
+
+        let pivot = a[k * n + k];
+        for j in 0..n {
+            a[k * n + j] = a[k * n + j] / pivot;
+            inv[k * n + j] = inv[k * n + j] / pivot;
+        }
+        for i in 0..n {
+            if i == k {
+                continue;
+            }
+            let factor = a[i * n + k];
+            for j in 0..n {
+                a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                inv[i * n + j] = inv[i * n + j] - factor * inv[k * n + j];
+            }
+        }
+    }
+    Ok(())
+}
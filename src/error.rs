@@ -0,0 +1,85 @@
+//! # mini_matrix
+//!
+//! Error type shared by the linear algebra routines.
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// Errors reported by the factorizations and solvers of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{LinalgError, Matrix, Vector};
+///
+/// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 4.0]]);
+/// let b = Vector::from([1.0, 2.0]);
+/// assert_eq!(a.solve(&b), Err(LinalgError::Singular));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LinalgError {
+    /// The matrix is singular (or numerically singular), so the system has no unique solution.
+    Singular,
+    /// The matrix is so badly conditioned that a computed solution cannot be trusted.
+    ///
+    /// `rcond` is the estimated reciprocal condition number in the 1-norm.
+    IllConditioned { rcond: f64 },
+    /// The matrix is not positive definite, so it has no Cholesky factorization.
+    NotPositiveDefinite,
+    /// The matrix is not symmetric, but the requested operation requires it to be.
+    NotSymmetric,
+    /// An iterative algorithm did not converge within its iteration limit.
+    NoConvergence,
+    /// An operand has the wrong shape for the operation.
+    ///
+    /// Shapes are given as `(rows, columns)`; vectors count as single columns.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// The operation requires a square matrix, but the matrix has the given shape.
+    NotSquare { rows: usize, cols: usize },
+    /// An entry `(row, column)` lies outside a matrix of shape `(rows, columns)`.
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize),
+    },
+    /// An intermediate result does not fit in the element type.
+    Overflow,
+    /// The SOR relaxation factor `omega` lies outside `(0, 2)`, where the method cannot
+    /// converge.
+    InvalidRelaxation { omega: f64 },
+}
+
+impl Display for LinalgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LinalgError::Singular => write!(f, "matrix is singular"),
+            LinalgError::IllConditioned { rcond } => {
+                write!(f, "matrix is ill-conditioned (rcond = {:e})", rcond)
+            }
+            LinalgError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            LinalgError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            LinalgError::NoConvergence => write!(f, "iteration did not converge"),
+            LinalgError::DimensionMismatch { expected, found } => write!(
+                f,
+                "dimension mismatch: expected {}x{}, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            LinalgError::NotSquare { rows, cols } => {
+                write!(f, "matrix is not square ({}x{})", rows, cols)
+            }
+            LinalgError::IndexOutOfBounds { index, shape } => write!(
+                f,
+                "index ({}, {}) out of bounds for a {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            LinalgError::Overflow => write!(f, "arithmetic overflow"),
+            LinalgError::InvalidRelaxation { omega } => {
+                write!(f, "relaxation factor {} is outside (0, 2)", omega)
+            }
+        }
+    }
+}
+
+impl Error for LinalgError {}
@@ -0,0 +1,128 @@
+//! # mini_matrix
+//!
+//! Modified Gram–Schmidt orthonormalisation of a set of vectors.
+
+use num::Float;
+use std::iter::Sum;
+
+use crate::Vector;
+
+/// An orthonormal basis built by [`gram_schmidt`], with the bookkeeping of which inputs were
+/// linearly dependent.
+///
+/// Basis vector `k` was produced from input `kept()[k]`, and the first `k + 1` basis vectors span
+/// the same space as the inputs kept up to that point.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{gram_schmidt, Vector};
+///
+/// let vectors = [
+///     Vector::from([3.0, 4.0, 0.0]),
+///     Vector::from([6.0, 8.0, 0.0]),
+///     Vector::from([1.0, 0.0, 0.0]),
+/// ];
+/// let basis = gram_schmidt(&vectors, 1e-12, false);
+/// assert_eq!(basis.kept(), &[0, 2]);
+/// assert_eq!(basis.dropped(), &[1]);
+/// assert!((basis.vectors()[0] - Vector::from([0.6, 0.8, 0.0])).norm() < 1e-15);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrthonormalBasis<T, const N: usize> {
+    vectors: Vec<Vector<T, N>>,
+    kept: Vec<usize>,
+    dropped: Vec<usize>,
+}
+
+impl<T, const N: usize> OrthonormalBasis<T, N> {
+    /// Returns the orthonormal vectors, in the order of the inputs they came from.
+    pub fn vectors(&self) -> &[Vector<T, N>] {
+        &self.vectors
+    }
+
+    /// Returns the indices of the inputs that contributed a basis vector.
+    pub fn kept(&self) -> &[usize] {
+        &self.kept
+    }
+
+    /// Returns the indices of the inputs that were dropped as linearly dependent on the
+    /// earlier ones.
+    pub fn dropped(&self) -> &[usize] {
+        &self.dropped
+    }
+
+    /// Returns the number of basis vectors, the dimension of the span of the inputs.
+    pub fn dimension(&self) -> usize {
+        self.vectors.len()
+    }
+}
+
+/// Orthonormalises `vectors` by the modified Gram–Schmidt process.
+///
+/// Each input has its components along the basis vectors found so far subtracted one at a time,
+/// using the partially orthogonalised vector for every projection; this is far less sensitive
+/// to rounding than the classical process, which projects the original input. With
+/// `reorthogonalize` the subtraction is done a second time, which restores orthogonality to
+/// working precision even for nearly dependent inputs at twice the cost.
+///
+/// An input is dropped as linearly dependent when what is left of it has norm at most
+/// `tolerance` times its original norm; zero inputs are always dropped.
+///
+/// # Arguments
+///
+/// * `vectors` - The vectors to orthonormalise, in order of priority
+/// * `tolerance` - The relative norm below which an input counts as dependent
+/// * `reorthogonalize` - Whether to run a second orthogonalisation pass
+///
+/// # Returns
+///
+/// An [`OrthonormalBasis`] of the span of `vectors`.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{gram_schmidt, Vector};
+///
+/// let vectors = [Vector::<f64, 2>::from([1.0, 1.0]), Vector::from([1.0, 0.0])];
+/// let basis = gram_schmidt(&vectors, 1e-12, true);
+/// assert_eq!(basis.dimension(), 2);
+/// let [q0, q1] = [basis.vectors()[0], basis.vectors()[1]];
+/// assert!(q0.dot(&q1).abs() < 1e-15);
+/// assert!((q1.norm() - 1.0).abs() < 1e-15);
+/// ```
+pub fn gram_schmidt<T, const N: usize>(
+    vectors: &[Vector<T, N>],
+    tolerance: T,
+    reorthogonalize: bool,
+) -> OrthonormalBasis<T, N>
+where
+    T: Float + Default + Sum,
+{
+    let passes = if reorthogonalize { 2 } else { 1 };
+    let mut basis = OrthonormalBasis {
+        vectors: Vec::new(),
+        kept: Vec::new(),
+        dropped: Vec::new(),
+    };
+
+    for (index, v) in vectors.iter().enumerate() {
+        let mut w = *v;
+        for _ in 0..passes {
+            for q in &basis.vectors {
+                let projection = q.dot(&w);
+                w -= *q * projection;
+            }
+        }
+
+        let norm = w.norm();
+        if norm <= tolerance * v.norm() || norm == T::zero() {
+            basis.dropped.push(index);
+            continue;
+        }
+        w = w * (T::one() / norm);
+        basis.vectors.push(w);
+        basis.kept.push(index);
+    }
+    basis
+}
@@ -0,0 +1,513 @@
+//! # mini_matrix
+//!
+//! Krylov subspace solvers for large linear systems: conjugate gradients, GMRES and BiCGSTAB.
+
+use num::Float;
+use std::iter::Sum;
+
+use crate::{DVector, LinalgError, LinearOperator, Preconditioner};
+
+/// Default number of inner iterations between GMRES restarts.
+const DEFAULT_RESTART: usize = 30;
+
+/// Settings shared by the iterative solvers.
+///
+/// A solve stops once the residual satisfies `‖b - A * x‖ <= tolerance * ‖b‖`, or after
+/// `max_iterations` iterations. The defaults are a tolerance of `√ε`, ten times as many
+/// iterations as the system has unknowns, no preconditioner and a zero initial guess.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{conjugate_gradient, DVector, JacobiPreconditioner, Matrix, SolverOptions};
+///
+/// let a = Matrix::<f64, 2, 2>::from([[4.0, 1.0], [1.0, 3.0]]);
+/// let b = DVector::from(vec![1.0, 2.0]);
+/// let jacobi = JacobiPreconditioner::new(&a).unwrap();
+/// let options = SolverOptions::new()
+///     .tolerance(1e-12)
+///     .max_iterations(10)
+///     .preconditioner(&jacobi);
+///
+/// let result = conjugate_gradient(&a, &b, &options).unwrap();
+/// assert!(result.converged());
+/// assert!((result.solution()[0] - 1.0 / 11.0).abs() < 1e-12);
+/// assert!((result.solution()[1] - 7.0 / 11.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy)]
+pub struct SolverOptions<'a, T> {
+    tolerance: T,
+    max_iterations: Option<usize>,
+    restart: usize,
+    preconditioner: Option<&'a dyn Preconditioner<T>>,
+    initial_guess: Option<&'a DVector<T>>,
+}
+
+impl<'a, T> SolverOptions<'a, T>
+where
+    T: Float,
+{
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self {
+            tolerance: T::epsilon().sqrt(),
+            max_iterations: None,
+            restart: DEFAULT_RESTART,
+            preconditioner: None,
+            initial_guess: None,
+        }
+    }
+
+    /// Sets the relative residual at which the iteration stops.
+    pub fn tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the maximum number of iterations.
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Sets the number of GMRES iterations between restarts; other solvers ignore it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `restart` is zero.
+    pub fn restart(mut self, restart: usize) -> Self {
+        assert!(restart > 0, "GMRES restart length must be positive");
+        self.restart = restart;
+        self
+    }
+
+    /// Sets the preconditioner.
+    pub fn preconditioner(mut self, preconditioner: &'a dyn Preconditioner<T>) -> Self {
+        self.preconditioner = Some(preconditioner);
+        self
+    }
+
+    /// Sets the starting point of the iteration.
+    pub fn initial_guess(mut self, initial_guess: &'a DVector<T>) -> Self {
+        self.initial_guess = Some(initial_guess);
+        self
+    }
+
+    /// Applies the preconditioner, or the identity if there is none.
+    fn precondition(&self, r: &DVector<T>) -> DVector<T> {
+        match self.preconditioner {
+            Some(preconditioner) => preconditioner.solve(r),
+            None => r.clone(),
+        }
+    }
+}
+
+impl<T> Default for SolverOptions<'_, T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The outcome of an iterative solve.
+///
+/// The solution is returned whether or not the iteration converged, so that a caller can accept
+/// a slightly less accurate answer or restart from it; check [`IterativeSolution::converged`]
+/// before relying on the requested accuracy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeSolution<T> {
+    solution: DVector<T>,
+    iterations: usize,
+    residual_history: Vec<T>,
+    converged: bool,
+}
+
+impl<T> IterativeSolution<T>
+where
+    T: Float,
+{
+    /// Returns the approximate solution `x`.
+    pub fn solution(&self) -> &DVector<T> {
+        &self.solution
+    }
+
+    /// Returns the number of iterations performed.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the residual norm `‖b - A * x‖` before the first iteration and after each one.
+    ///
+    /// GMRES reports the residual norm its least-squares problem predicts during a cycle, and
+    /// the true residual norm at the end of each cycle.
+    pub fn residual_history(&self) -> &[T] {
+        &self.residual_history
+    }
+
+    /// Returns the final residual norm `‖b - A * x‖`.
+    pub fn residual_norm(&self) -> T {
+        *self.residual_history.last().unwrap()
+    }
+
+    /// Returns whether the residual reached the requested tolerance.
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
+
+fn dot<T: Float + Sum>(x: &[T], y: &[T]) -> T {
+    x.iter().zip(y.iter()).map(|(&a, &b)| a * b).sum()
+}
+
+fn norm<T: Float + Sum>(x: &[T]) -> T {
+    dot(x, x).sqrt()
+}
+
+/// Adds `alpha * x` to `y`.
+fn axpy<T: Float>(alpha: T, x: &[T], y: &mut [T]) {
+    for (y, &x) in y.iter_mut().zip(x.iter()) {
+        *y = *y + alpha * x;
+    }
+}
+
+/// Computes `b - A * x`.
+fn residual<T, A>(a: &A, b: &DVector<T>, x: &DVector<T>) -> DVector<T>
+where
+    T: Float + Default,
+    A: LinearOperator<T> + ?Sized,
+{
+    let mut r = b.clone();
+    axpy(-T::one(), &a.apply(x), &mut r);
+    r
+}
+
+/// Checks the shapes of the system and returns the initial guess, the iteration limit and the
+/// absolute residual at which to stop.
+fn prepare<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    options: &SolverOptions<T>,
+) -> Result<(DVector<T>, usize, T), LinalgError>
+where
+    T: Float + Default + Sum,
+    A: LinearOperator<T> + ?Sized,
+{
+    let (rows, cols) = a.shape();
+    if rows != cols {
+        return Err(LinalgError::NotSquare { rows, cols });
+    }
+    for vector in std::iter::once(b).chain(options.initial_guess) {
+        if vector.len() != rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (rows, 1),
+                found: (vector.len(), 1),
+            });
+        }
+    }
+    let x = match options.initial_guess {
+        Some(guess) => guess.clone(),
+        None => DVector::zero(rows),
+    };
+    let max_iterations = options.max_iterations.unwrap_or(10 * rows);
+    Ok((x, max_iterations, options.tolerance * norm(b)))
+}
+
+/// Solves `A * x = b` for a symmetric positive definite `A` by the conjugate gradient method.
+///
+/// Each iteration costs one product with `A` and one preconditioner solve. In exact arithmetic
+/// the method terminates after at most `n` iterations; in practice the number of iterations
+/// grows with the square root of the condition number of the (preconditioned) matrix. The
+/// preconditioner must be symmetric positive definite as well.
+///
+/// # Returns
+///
+/// Returns `Ok(IterativeSolution)`, or:
+/// - [`LinalgError::NotSquare`] if `a` is not square,
+/// - [`LinalgError::DimensionMismatch`] if `b` or the initial guess has the wrong length,
+/// - [`LinalgError::NotPositiveDefinite`] if a search direction with non-positive curvature
+///   `pᵀ * A * p <= 0` is found.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{conjugate_gradient, CsrMatrix, DVector, Matrix, SolverOptions};
+///
+/// let a = Matrix::<f64, 3, 3>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+/// let a = CsrMatrix::from(a);
+/// let b = DVector::from(vec![1.0, 0.0, 1.0]);
+/// let result = conjugate_gradient(&a, &b, &SolverOptions::new()).unwrap();
+/// assert!(result.converged());
+/// assert!(result.iterations() <= 3);
+/// for &x in result.solution().iter() {
+///     assert!((x - 1.0).abs() < 1e-8);
+/// }
+/// ```
+pub fn conjugate_gradient<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    options: &SolverOptions<T>,
+) -> Result<IterativeSolution<T>, LinalgError>
+where
+    T: Float + Default + Sum,
+    A: LinearOperator<T> + ?Sized,
+{
+    let (mut x, max_iterations, threshold) = prepare(a, b, options)?;
+    let mut r = residual(a, b, &x);
+    let mut residual_history = vec![norm(&r)];
+    let mut z = options.precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    let mut iterations = 0;
+    while *residual_history.last().unwrap() > threshold && iterations < max_iterations {
+        let q = a.apply(&p);
+        let curvature = dot(&p, &q);
+        if curvature <= T::zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        let alpha = rz / curvature;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &q, &mut r);
+        residual_history.push(norm(&r));
+        iterations += 1;
+
+        z = options.precondition(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (p, &z) in p.iter_mut().zip(z.iter()) {
+            *p = z + beta * *p;
+        }
+    }
+
+    let converged = *residual_history.last().unwrap() <= threshold;
+    Ok(IterativeSolution {
+        solution: x,
+        iterations,
+        residual_history,
+        converged,
+    })
+}
+
+/// Solves `A * x = b` for a general square `A` by the restarted generalised minimal residual
+/// method, GMRES(m).
+///
+/// Each iteration extends an orthonormal Krylov basis by one vector and picks the point of the
+/// current affine subspace with the smallest residual, so the residual never increases within a
+/// cycle. Storage and orthogonalisation cost grow with the basis, so it is discarded and the
+/// method restarted from the current point every [`SolverOptions::restart`] iterations. The
+/// preconditioner is applied on the right, so the reported residuals are those of the original
+/// system.
+///
+/// # Returns
+///
+/// Returns `Ok(IterativeSolution)`, [`LinalgError::NotSquare`] if `a` is not square, or
+/// [`LinalgError::DimensionMismatch`] if `b` or the initial guess has the wrong length.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{gmres, DVector, Matrix, SolverOptions};
+///
+/// let a = Matrix::<f64, 3, 3>::from([[1.0, 2.0, 0.0], [0.0, 1.0, 3.0], [4.0, 0.0, 1.0]]);
+/// let b = DVector::from(vec![3.0, 4.0, 5.0]);
+/// let result = gmres(&a, &b, &SolverOptions::new().tolerance(1e-12)).unwrap();
+/// assert!(result.converged());
+/// for &x in result.solution().iter() {
+///     assert!((x - 1.0).abs() < 1e-10);
+/// }
+/// ```
+pub fn gmres<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    options: &SolverOptions<T>,
+) -> Result<IterativeSolution<T>, LinalgError>
+where
+    T: Float + Default + Sum,
+    A: LinearOperator<T> + ?Sized,
+{
+    let (mut x, max_iterations, threshold) = prepare(a, b, options)?;
+    let mut r = residual(a, b, &x);
+    let mut residual_norm = norm(&r);
+    let mut residual_history = vec![residual_norm];
+
+    let mut iterations = 0;
+    while residual_norm > threshold && iterations < max_iterations {
+        let restart = options.restart.min(max_iterations - iterations);
+
+        // Arnoldi process on `A * M⁻¹`, with the Hessenberg matrix reduced to upper triangular
+        // form by Givens rotations as it grows. `h[j]` is column `j` of that matrix.
+        let mut basis = vec![DVector::zero(b.len())];
+        axpy(T::one() / residual_norm, &r, &mut basis[0]);
+        let mut h: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![residual_norm];
+        for j in 0..restart {
+            let mut w = a.apply(&options.precondition(&basis[j]));
+            let mut column = Vec::with_capacity(j + 2);
+            for v in basis.iter() {
+                let coefficient = dot(v, &w);
+                axpy(-coefficient, v, &mut w);
+                column.push(coefficient);
+            }
+            let w_norm = norm(&w);
+            column.push(w_norm);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (upper, lower) = (column[i], column[i + 1]);
+                column[i] = c * upper + s * lower;
+                column[i + 1] = c * lower - s * upper;
+            }
+            let (upper, lower) = (column[j], column[j + 1]);
+            let radius = upper.hypot(lower);
+            let (c, s) = if radius == T::zero() {
+                (T::one(), T::zero())
+            } else {
+                (upper / radius, lower / radius)
+            };
+            column[j] = radius;
+            column.pop();
+            rotations.push((c, s));
+            g.push(-s * g[j]);
+            g[j] = c * g[j];
+            h.push(column);
+
+            iterations += 1;
+            residual_history.push(g[j + 1].abs());
+            if g[j + 1].abs() <= threshold || w_norm == T::zero() {
+                break;
+            }
+            for w in w.iter_mut() {
+                *w = *w / w_norm;
+            }
+            basis.push(w);
+        }
+
+        // Back substitution for the coefficients of the update in the Krylov basis.
+        let k = h.len();
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let mut sum = g[i];
+            for (l, &y) in y.iter().enumerate().skip(i + 1) {
+                sum = sum - h[l][i] * y;
+            }
+            y[i] = if h[i][i] == T::zero() {
+                T::zero()
+            } else {
+                sum / h[i][i]
+            };
+        }
+        let mut update = DVector::zero(b.len());
+        for (v, &y) in basis.iter().zip(y.iter()) {
+            axpy(y, v, &mut update);
+        }
+        axpy(T::one(), &options.precondition(&update), &mut x);
+
+        r = residual(a, b, &x);
+        residual_norm = norm(&r);
+        *residual_history.last_mut().unwrap() = residual_norm;
+    }
+
+    Ok(IterativeSolution {
+        solution: x,
+        iterations,
+        residual_history,
+        converged: residual_norm <= threshold,
+    })
+}
+
+/// Solves `A * x = b` for a general square `A` by the biconjugate gradient stabilised method,
+/// BiCGSTAB.
+///
+/// Each iteration costs two products with `A` and two preconditioner solves, and, unlike GMRES,
+/// the storage does not grow with the number of iterations. The residual is not guaranteed to
+/// decrease monotonically, and the method can break down when an inner product vanishes; it
+/// then stops early and reports that it did not converge. The preconditioner is applied on the
+/// right.
+///
+/// # Returns
+///
+/// Returns `Ok(IterativeSolution)`, [`LinalgError::NotSquare`] if `a` is not square, or
+/// [`LinalgError::DimensionMismatch`] if `b` or the initial guess has the wrong length.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{bicgstab, DVector, Matrix, SolverOptions};
+///
+/// let a = Matrix::<f64, 3, 3>::from([[4.0, 1.0, 0.0], [-1.0, 4.0, 1.0], [0.0, -1.0, 4.0]]);
+/// let b = DVector::from(vec![5.0, 4.0, 3.0]);
+/// let result = bicgstab(&a, &b, &SolverOptions::new().tolerance(1e-12)).unwrap();
+/// assert!(result.converged());
+/// for &x in result.solution().iter() {
+///     assert!((x - 1.0).abs() < 1e-10);
+/// }
+/// ```
+pub fn bicgstab<T, A>(
+    a: &A,
+    b: &DVector<T>,
+    options: &SolverOptions<T>,
+) -> Result<IterativeSolution<T>, LinalgError>
+where
+    T: Float + Default + Sum,
+    A: LinearOperator<T> + ?Sized,
+{
+    let (mut x, max_iterations, threshold) = prepare(a, b, options)?;
+    let mut r = residual(a, b, &x);
+    let mut residual_history = vec![norm(&r)];
+    let shadow = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut p = DVector::zero(b.len());
+    let mut v = DVector::zero(b.len());
+
+    let mut iterations = 0;
+    while *residual_history.last().unwrap() > threshold && iterations < max_iterations {
+        let rho_next = dot(&shadow, &r);
+        if rho_next == T::zero() || omega == T::zero() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for ((p, &r), &v) in p.iter_mut().zip(r.iter()).zip(v.iter()) {
+            *p = r + beta * (*p - omega * v);
+        }
+
+        let p_hat = options.precondition(&p);
+        v = a.apply(&p_hat);
+        let shadow_v = dot(&shadow, &v);
+        if shadow_v == T::zero() {
+            break;
+        }
+        alpha = rho / shadow_v;
+        axpy(alpha, &p_hat, &mut x);
+        axpy(-alpha, &v, &mut r);
+        iterations += 1;
+        let half_step = norm(&r);
+        if half_step <= threshold {
+            residual_history.push(half_step);
+            break;
+        }
+
+        let s_hat = options.precondition(&r);
+        let t = a.apply(&s_hat);
+        let tt = dot(&t, &t);
+        omega = if tt == T::zero() {
+            T::zero()
+        } else {
+            dot(&t, &r) / tt
+        };
+        axpy(omega, &s_hat, &mut x);
+        axpy(-omega, &t, &mut r);
+        residual_history.push(norm(&r));
+    }
+
+    let converged = *residual_history.last().unwrap() <= threshold;
+    Ok(IterativeSolution {
+        solution: x,
+        iterations,
+        residual_history,
+        converged,
+    })
+}
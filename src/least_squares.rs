@@ -0,0 +1,184 @@
+//! # mini_matrix
+//!
+//! Moore–Penrose pseudoinverse and least-squares solutions.
+
+use num::traits::float::TotalOrder;
+use num::Float;
+use std::iter::Sum;
+
+use crate::{LinalgError, Matrix, Svd, Vector};
+
+/// The minimum-norm least-squares solution of `A * x = b` for an `M x N` matrix `A`.
+///
+/// Along with the solution `x`, the residual `b - A * x` and the numerical rank of `A` are kept,
+/// so callers can tell an exact solution from a best fit and a unique solution from one of many.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{Matrix, Vector};
+///
+/// // The line through (0, 1), (1, 3), (2, 4) that best fits in the least-squares sense.
+/// let a = Matrix::<f64, 3, 2>::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+/// let fit = a.least_squares(&Vector::from([1.0, 3.0, 4.0])).unwrap();
+/// assert_eq!(fit.rank(), 2);
+/// assert!((fit.solution()[0] - 7.0 / 6.0).abs() < 1e-12);
+/// assert!((fit.solution()[1] - 1.5).abs() < 1e-12);
+/// assert!((fit.residual_norm() - (1.0_f64 / 6.0).sqrt()).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeastSquares<T, const M: usize, const N: usize> {
+    solution: Vector<T, N>,
+    residual: Vector<T, M>,
+    residual_norm: T,
+    rank: usize,
+}
+
+/// Forms `A⁺ = V * Σ⁺ * Uᵀ`, inverting the singular values greater than `tolerance` and
+/// dropping the others.
+fn pseudo_inverse_from_svd<T, const M: usize, const N: usize>(
+    svd: &Svd<T, M, N>,
+    tolerance: T,
+) -> Matrix<T, N, M>
+where
+    T: Float + Default + Sum,
+{
+    let (u, vt) = (svd.u(), svd.vt());
+    let mut pseudo_inverse = Matrix::zero();
+    for (k, &value) in svd.singular_values().iter().enumerate() {
+        if value <= tolerance {
+            break;
+        }
+        for i in 0..N {
+            let scaled = vt[(k, i)] / value;
+            for j in 0..M {
+                pseudo_inverse[(i, j)] = pseudo_inverse[(i, j)] + scaled * u[(j, k)];
+            }
+        }
+    }
+    pseudo_inverse
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Default + Sum + TotalOrder,
+{
+    /// Computes the Moore–Penrose pseudoinverse `A⁺` from the singular value decomposition.
+    ///
+    /// Singular values up to `max(M, N) * ε * σ_max` are treated as zero, which makes the result
+    /// continuous under rounding errors for rank-deficient matrices. For a square invertible
+    /// matrix the pseudoinverse is the inverse; for a matrix with full column rank it is the
+    /// left inverse `(AᵀA)⁻¹Aᵀ`, and for full row rank the right inverse `Aᵀ(AAᵀ)⁻¹`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)` on success, or [`LinalgError::NoConvergence`] if [`Matrix::svd`]
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 1, 2>::from([[3.0, 4.0]]);
+    /// let pinv = a.pseudo_inverse().unwrap();
+    /// assert!((pinv[(0, 0)] - 0.12).abs() < 1e-12);
+    /// assert!((pinv[(1, 0)] - 0.16).abs() < 1e-12);
+    /// ```
+    pub fn pseudo_inverse(&self) -> Result<Matrix<T, N, M>, LinalgError> {
+        let svd = self.svd()?;
+        Ok(pseudo_inverse_from_svd(&svd, svd.default_tolerance()))
+    }
+
+    /// Computes the Moore–Penrose pseudoinverse, treating singular values up to `tolerance` as
+    /// zero.
+    ///
+    /// A larger tolerance regularises the result by discarding the directions in which the matrix
+    /// is nearly singular. Returns [`LinalgError::NoConvergence`] if [`Matrix::svd`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 0.0], [0.0, 1e-9]]);
+    /// let pinv = a.pseudo_inverse_with_tolerance(1e-6).unwrap();
+    /// assert_eq!(pinv.store, [[1.0, 0.0], [0.0, 0.0]]);
+    /// ```
+    pub fn pseudo_inverse_with_tolerance(
+        &self,
+        tolerance: T,
+    ) -> Result<Matrix<T, N, M>, LinalgError> {
+        Ok(pseudo_inverse_from_svd(&self.svd()?, tolerance))
+    }
+
+    /// Solves `A * x = b` in the least-squares sense.
+    ///
+    /// The solution minimises `‖A * x - b‖₂`, and among all minimisers it is the one of smallest
+    /// norm, `x = A⁺ * b`. This covers overdetermined systems (a best fit), underdetermined
+    /// systems (the smallest of infinitely many exact solutions) and rank-deficient ones alike.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(LeastSquares)` on success, or [`LinalgError::NoConvergence`] if
+    /// [`Matrix::svd`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// // One equation, two unknowns: x + y = 2 is solved exactly by the shortest x = (1, 1).
+    /// let a = Matrix::<f64, 1, 2>::from([[1.0, 1.0]]);
+    /// let fit = a.least_squares(&Vector::from([2.0])).unwrap();
+    /// assert!((fit.solution()[0] - 1.0).abs() < 1e-12);
+    /// assert!((fit.solution()[1] - 1.0).abs() < 1e-12);
+    /// assert!(fit.residual_norm() < 1e-12);
+    /// ```
+    pub fn least_squares(&self, b: &Vector<T, M>) -> Result<LeastSquares<T, M, N>, LinalgError> {
+        let svd = self.svd()?;
+        let tolerance = svd.default_tolerance();
+        let pseudo_inverse = pseudo_inverse_from_svd(&svd, tolerance);
+
+        let mut solution = Vector::<T, N>::zero();
+        for i in 0..N {
+            solution[i] = (0..M).map(|j| pseudo_inverse[(i, j)] * b[j]).sum();
+        }
+        let mut residual = *b;
+        for i in 0..M {
+            residual[i] = residual[i] - (0..N).map(|j| self[(i, j)] * solution[j]).sum();
+        }
+
+        Ok(LeastSquares {
+            solution,
+            residual,
+            residual_norm: residual.norm(),
+            rank: svd.rank(tolerance),
+        })
+    }
+}
+
+impl<T, const M: usize, const N: usize> LeastSquares<T, M, N>
+where
+    T: Float + Default + Sum,
+{
+    /// Returns the minimum-norm least-squares solution `x`.
+    pub fn solution(&self) -> Vector<T, N> {
+        self.solution
+    }
+
+    /// Returns the residual `b - A * x`.
+    pub fn residual(&self) -> Vector<T, M> {
+        self.residual
+    }
+
+    /// Returns the Euclidean norm of the residual, zero when the system is solved exactly.
+    pub fn residual_norm(&self) -> T {
+        self.residual_norm
+    }
+
+    /// Returns the numerical rank of `A`; the solution is unique only if it equals `N`.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
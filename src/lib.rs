@@ -1,10 +1,47 @@
 // lib.rs
 
+mod block;
+mod cholesky;
+mod dynamic;
+mod echelon;
+mod eigen;
+mod elimination;
+mod error;
+mod gram_schmidt;
+mod krylov;
+mod least_squares;
+mod lu;
 mod matrix;
+mod matrix_function;
+mod norm;
 mod operations;
+mod operator;
+mod power;
+mod preconditioner;
+mod product;
+mod qr;
+mod sparse;
+mod stationary;
+mod subspace;
+mod svd;
 mod utility;
 mod vector;
 
+pub use cholesky::{Cholesky, Ldlt};
+pub use dynamic::{DMatrix, DVector};
+pub use echelon::RowEchelon;
+pub use eigen::{Schur, SymmetricEigen};
+pub use error::LinalgError;
+pub use gram_schmidt::{gram_schmidt, OrthonormalBasis};
+pub use krylov::{bicgstab, conjugate_gradient, gmres, IterativeSolution, SolverOptions};
+pub use least_squares::LeastSquares;
+pub use lu::Lu;
 pub use matrix::Matrix;
 pub use operations::*;
+pub use operator::{FnOperator, LinearOperator};
+pub use preconditioner::{IncompleteCholesky, JacobiPreconditioner, Preconditioner};
+pub use qr::Qr;
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
+pub use stationary::StationarySolution;
+pub use svd::Svd;
 pub use vector::Vector;
@@ -0,0 +1,376 @@
+//! # mini_matrix
+//!
+//! LU decomposition with partial pivoting.
+
+use num::Float;
+
+use crate::{LinalgError, Matrix, Vector};
+
+/// The LU factorization `P * A = L * U` of a square matrix.
+///
+/// `L` is unit lower triangular, `U` is upper triangular and `P` is the row permutation chosen by
+/// partial pivoting. Both triangular factors are stored packed in a single matrix, so computing
+/// the factorization once and reusing it for many right-hand sides costs `O(N²)` per solve
+/// instead of `O(N³)`.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{Matrix, Vector};
+///
+/// let a = Matrix::<f64, 2, 2>::from([[2.0, 1.0], [4.0, 3.0]]);
+/// let lu = a.lu();
+///
+/// let x = lu.solve(&Vector::from([3.0, 7.0])).unwrap();
+/// assert!((x[0] - 1.0).abs() < 1e-12);
+/// assert!((x[1] - 1.0).abs() < 1e-12);
+/// assert!((lu.determinant() - 2.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    lu: Matrix<T, N, N>,
+    permutation: [usize; N],
+    sign: T,
+    tolerance: T,
+    norm: T,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default,
+{
+    /// Computes the LU decomposition of the matrix with partial pivoting.
+    ///
+    /// At every step the row holding the largest-magnitude entry of the current column is used
+    /// as the pivot. The factorization always succeeds; singular matrices produce a `U` with a
+    /// (numerically) zero diagonal entry, which is reported by [`Lu::is_singular`] and by the
+    /// solving methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// let lu = a.lu();
+    /// assert_eq!(lu.permutation(), [1, 0]);
+    /// assert_eq!(lu.u().store, [[3.0, 4.0], [0.0, 2.0 - 4.0 / 3.0]]);
+    /// ```
+    pub fn lu(&self) -> Lu<T, N> {
+        let mut lu = *self;
+        let mut permutation: [usize; N] = std::array::from_fn(|i| i);
+        let mut sign = T::one();
+
+        for k in 0..N {
+            let pivot_row = (k + 1..N).fold(k, |best, i| {
+                if lu[(i, k)].abs() > lu[(best, k)].abs() {
+                    i
+                } else {
+                    best
+                }
+            });
+            if pivot_row != k {
+                lu.store.swap(k, pivot_row);
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = lu[(k, k)];
+            if pivot == T::zero() {
+                continue;
+            }
+            for i in k + 1..N {
+                let factor = lu[(i, k)] / pivot;
+                lu[(i, k)] = factor;
+                for j in k + 1..N {
+                    lu[(i, j)] = lu[(i, j)] - factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Lu {
+            lu,
+            permutation,
+            sign,
+            tolerance: self.default_tolerance(),
+            norm: self.norm_1(),
+        }
+    }
+
+    /// Solves the linear system `A * x = b`.
+    ///
+    /// The system is solved through an LU factorization with partial pivoting. Before solving,
+    /// the reciprocal condition number is estimated with [`Lu::rcond`]; if it is smaller than the
+    /// machine epsilon of `T`, the computed solution would carry no correct digits and an error is
+    /// returned instead.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vector)` with the solution, [`LinalgError::Singular`] if the matrix is
+    /// singular, or [`LinalgError::IllConditioned`] if it is too badly conditioned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[3.0, 2.0], [1.0, 2.0]]);
+    /// let x = a.solve(&Vector::from([5.0, 5.0])).unwrap();
+    /// assert!((x[0] - 0.0).abs() < 1e-12);
+    /// assert!((x[1] - 2.5).abs() < 1e-12);
+    /// ```
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        let lu = self.lu();
+        lu.check_conditioning()?;
+        lu.solve(b)
+    }
+
+    /// Solves `A * X = B` for several right-hand sides given as the columns of `B`.
+    ///
+    /// Errors are reported in the same way as for [`Matrix::solve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[2.0, 0.0], [0.0, 4.0]]);
+    /// let b = Matrix::from([[2.0, 4.0, 6.0], [4.0, 8.0, 12.0]]);
+    /// let x = a.solve_matrix(&b).unwrap();
+    /// assert_eq!(x.store, [[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]);
+    /// ```
+    pub fn solve_matrix<const K: usize>(
+        &self,
+        b: &Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, LinalgError> {
+        let lu = self.lu();
+        lu.check_conditioning()?;
+        lu.solve_matrix(b)
+    }
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Float + Default,
+{
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T, N, N> {
+        let mut l = Matrix::identity();
+        for i in 0..N {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        l
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> Matrix<T, N, N> {
+        let mut u = Matrix::zero();
+        for i in 0..N {
+            for j in i..N {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        u
+    }
+
+    /// Returns the row permutation: row `i` of `P * A` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    /// Returns the permutation matrix `P` such that `P * A = L * U`.
+    pub fn p(&self) -> Matrix<T, N, N> {
+        let mut p = Matrix::zero();
+        for (i, &row) in self.permutation.iter().enumerate() {
+            p[(i, row)] = T::one();
+        }
+        p
+    }
+
+    /// Returns the sign of the permutation: `1` for an even number of row swaps, `-1` otherwise.
+    pub fn sign(&self) -> T {
+        self.sign
+    }
+
+    /// Returns `true` if a diagonal entry of `U` is zero within the tolerance of
+    /// [`Matrix::default_tolerance`] of the factored matrix.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.lu[(i, i)].abs() <= self.tolerance)
+    }
+
+    /// Computes the determinant of the factored matrix as `sign * u₀₀ * u₁₁ * … * uₙₙ`.
+    pub fn determinant(&self) -> T {
+        (0..N).fold(self.sign, |acc, i| acc * self.lu[(i, i)])
+    }
+
+    /// Estimates the reciprocal condition number `1 / (‖A‖₁ ‖A⁻¹‖₁)` of the factored matrix.
+    ///
+    /// `‖A⁻¹‖₁` is estimated with Hager's method, which only needs a handful of solves with the
+    /// stored factors instead of forming the inverse. The result is close to `1` for
+    /// well-conditioned matrices and close to `0` for nearly singular ones; it is exactly `0` when
+    /// the matrix is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 0.0], [0.0, 1e-10]]);
+    /// assert!((a.lu().rcond() - 1e-10).abs() < 1e-20);
+    /// ```
+    pub fn rcond(&self) -> T {
+        if self.is_singular() {
+            return T::zero();
+        }
+        if N == 0 {
+            return T::one();
+        }
+
+        let n = T::from(N).unwrap();
+        let mut x = Vector::from([T::one() / n; N]);
+        let mut estimate = T::zero();
+        for iteration in 0..5 {
+            let y = self.substitute(&x);
+            estimate = y.iter().fold(T::zero(), |acc, v| acc + v.abs());
+
+            let mut signs = Vector::zero();
+            for i in 0..N {
+                signs[i] = if y[i] < T::zero() {
+                    -T::one()
+                } else {
+                    T::one()
+                };
+            }
+            let z = self.substitute_transpose(&signs);
+            let j = (1..N).fold(
+                0,
+                |best, i| if z[i].abs() > z[best].abs() { i } else { best },
+            );
+            let zx = (0..N).fold(T::zero(), |acc, i| acc + z[i] * x[i]);
+            if iteration > 0 && z[j].abs() <= zx {
+                break;
+            }
+            x = Vector::zero();
+            x[j] = T::one();
+        }
+        T::one() / (self.norm * estimate)
+    }
+
+    /// Returns [`LinalgError::Singular`] or [`LinalgError::IllConditioned`] if solutions computed
+    /// from this factorization cannot be trusted.
+    fn check_conditioning(&self) -> Result<(), LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let rcond = self.rcond();
+        if rcond < T::epsilon() {
+            return Err(LinalgError::IllConditioned {
+                rcond: rcond.to_f64().unwrap(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Solves `A * x = b` by forward and back substitution, without checking for singularity.
+    fn substitute(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let mut x = Vector::zero();
+        for i in 0..N {
+            let mut sum = b[self.permutation[i]];
+            for j in 0..i {
+                sum = sum - self.lu[(i, j)] * x[j];
+            }
+            x[i] = sum;
+        }
+        for i in (0..N).rev() {
+            let mut sum = x[i];
+            for j in i + 1..N {
+                sum = sum - self.lu[(i, j)] * x[j];
+            }
+            x[i] = sum / self.lu[(i, i)];
+        }
+        x
+    }
+
+    /// Solves `Aᵀ * x = b`, using `Aᵀ = Uᵀ Lᵀ P`, without checking for singularity.
+    fn substitute_transpose(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let mut w = Vector::<T, N>::zero();
+        for i in 0..N {
+            let mut sum = b[i];
+            for j in 0..i {
+                sum = sum - self.lu[(j, i)] * w[j];
+            }
+            w[i] = sum / self.lu[(i, i)];
+        }
+        for i in (0..N).rev() {
+            let mut sum = w[i];
+            for j in i + 1..N {
+                sum = sum - self.lu[(j, i)] * w[j];
+            }
+            w[i] = sum;
+        }
+        let mut x = Vector::zero();
+        for i in 0..N {
+            x[self.permutation[i]] = w[i];
+        }
+        x
+    }
+
+    /// Solves `A * x = b` for `x` using the stored factorization.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vector)` with the solution, or [`LinalgError::Singular`] if the matrix is
+    /// singular.
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        Ok(self.substitute(b))
+    }
+
+    /// Solves `A * X = B` for every column of `B` using the stored factorization.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)` with the solutions as columns, or [`LinalgError::Singular`] if the
+    /// matrix is singular.
+    pub fn solve_matrix<const K: usize>(
+        &self,
+        b: &Matrix<T, N, K>,
+    ) -> Result<Matrix<T, N, K>, LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+
+        let mut x = Matrix::zero();
+        for c in 0..K {
+            for i in 0..N {
+                let mut sum = b[(self.permutation[i], c)];
+                for j in 0..i {
+                    sum = sum - self.lu[(i, j)] * x[(j, c)];
+                }
+                x[(i, c)] = sum;
+            }
+            for i in (0..N).rev() {
+                let mut sum = x[(i, c)];
+                for j in i + 1..N {
+                    sum = sum - self.lu[(i, j)] * x[(j, c)];
+                }
+                x[(i, c)] = sum / self.lu[(i, i)];
+            }
+        }
+        Ok(x)
+    }
+
+    /// Computes the inverse of the factored matrix by solving `A * X = I`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)` if the inverse exists, or [`LinalgError::Singular`] if the matrix is
+    /// singular.
+    pub fn inverse(&self) -> Result<Matrix<T, N, N>, LinalgError> {
+        self.solve_matrix(&Matrix::identity())
+    }
+}
@@ -94,12 +94,12 @@ fn ex02() {
     let v2 = Vector::from([0., 10., -100.]);
     println!(
         "{}",
-        linear_combination(&mut [e1, e2, e3], &[10., -2., 0.5])
+        linear_combination(&[e1, e2, e3], &[10., -2., 0.5])
     );
     // [10.]
     // [-2.]
     // [0.5]
-    println!("{}", linear_combination(&mut [v1, v2], &[10., -2.]));
+    println!("{}", linear_combination(&[v1, v2], &[10., -2.]));
     // [10.]
     // [0.]
     // [230.]
@@ -2,13 +2,15 @@
 //!
 //! A mini linear algebra library implemented in Rust.
 
-use num::{Float, Num};
+use num::rational::Ratio;
+use num::{BigInt, CheckedDiv, CheckedMul, CheckedSub, Float, Integer, Num, Zero};
 use std::fmt::{Debug, Display};
 
-use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
-use crate::Vector;
+use crate::elimination;
+use crate::{LinalgError, Vector};
 
 /// A generic matrix type with `M` rows and `N` columns.
 ///
@@ -28,7 +30,7 @@ pub struct Matrix<T, const M: usize, const N: usize> {
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    T: Copy + Default,
+    T: Clone + Default,
 {
     /// Creates a new `Matrix` from the given 2D array.
     ///
@@ -69,18 +71,47 @@ where
     /// ```
     pub fn zero() -> Self {
         Self {
-            store: [[T::default(); N]; M],
+            store: std::array::from_fn(|_| std::array::from_fn(|_| T::default())),
         }
     }
 
-    pub fn from_vecs(vecs: Vec<Vec<T>>) -> Self {
-        let mut store = [[T::default(); N]; M];
-        for (i, vec) in vecs.iter().enumerate() {
-            for (j, elem) in vec.iter().enumerate() {
-                store[i][j] = *elem;
+    /// Creates a new `Matrix` from a vector of rows.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)` if there are exactly `M` rows of exactly `N` elements each, or
+    /// [`LinalgError::DimensionMismatch`] with the offending shape otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    ///
+    /// let a = Matrix::<i32, 2, 2>::from_vecs(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(a.store, [[1, 2], [3, 4]]);
+    ///
+    /// let ragged = Matrix::<i32, 2, 2>::from_vecs(vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(
+    ///     ragged,
+    ///     Err(LinalgError::DimensionMismatch { expected: (2, 2), found: (2, 1) })
+    /// );
+    /// ```
+    pub fn from_vecs(vecs: Vec<Vec<T>>) -> Result<Self, LinalgError> {
+        let mismatch = |found| LinalgError::DimensionMismatch {
+            expected: (M, N),
+            found,
+        };
+        if vecs.len() != M {
+            return Err(mismatch((vecs.len(), vecs.first().map_or(0, Vec::len))));
+        }
+        let mut store = Self::zero().store;
+        for (row, vec) in store.iter_mut().zip(vecs.iter()) {
+            if vec.len() != N {
+                return Err(mismatch((M, vec.len())));
             }
+            row.clone_from_slice(vec);
         }
-        Self { store }
+        Ok(Self { store })
     }
 
     #[allow(dead_code)]
@@ -91,7 +122,7 @@ where
         let mut result = Matrix::<T, M, N>::zero();
         for i in 0..M {
             for j in 0..N {
-                result[(i, j)] = f(self[(i, j)]);
+                result[(i, j)] = f(self[(i, j)].clone());
             }
         }
         result
@@ -201,7 +232,7 @@ impl<T, const M: usize, const N: usize> Index<(usize, usize)> for Matrix<T, M, N
     /// assert_eq!(matrix[(1, 0)], 3);
     /// ```
     fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.store.index(index.0).index(index.1)
+        self.store.index(index.0).index(index.1)
     }
 }
 
@@ -266,7 +297,7 @@ where
     /// assert_eq!(c.store, [[6, 8], [10, 12]]);
     /// ```
     fn add(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for (l_row, r_row) in result.store.iter_mut().zip(rhs.store.iter()) {
             for (l, r) in l_row.iter_mut().zip(r_row.iter()) {
                 *l += *r;
@@ -295,7 +326,7 @@ where
     /// assert_eq!(c.store, [[4, 4], [4, 4]]);
     /// ```
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for (l_row, r_row) in result.store.iter_mut().zip(rhs.store.iter()) {
             for (l, r) in l_row.iter_mut().zip(r_row.iter()) {
                 *l -= *r;
@@ -323,7 +354,7 @@ where
     /// assert_eq!(b.store, [[2, 4], [6, 8]]);
     /// ```
     fn mul(self, rhs: T) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for row in result.store.iter_mut() {
             for elem in row.iter_mut() {
                 *elem *= rhs;
@@ -350,6 +381,9 @@ where
     /// let v = Vector::<i32, 2>::from([5, 6]);
     /// let result = a * v;
     /// assert_eq!(result.store, [17, 39]);
+    ///
+    /// let b = Matrix::<i32, 3, 2>::from([[1, 0], [0, 1], [1, 1]]);
+    /// assert_eq!((b * v).store, [5, 6, 11]);
     /// ```
     fn mul(self, rhs: Vector<T, N>) -> Self::Output {
         let mut result = Vector::zero();
@@ -362,14 +396,17 @@ where
     }
 }
 
-impl<T, const M: usize, const N: usize> Mul<Matrix<T, N, N>> for Matrix<T, M, N>
+impl<T, const M: usize, const N: usize, const P: usize> Mul<Matrix<T, N, P>> for Matrix<T, M, N>
 where
     T: MulAssign + AddAssign + Copy + Num + Default,
 {
-    type Output = Self;
+    type Output = Matrix<T, M, P>;
 
     /// Multiplies two matrices.
     ///
+    /// An `M x N` matrix can be multiplied by any `N x P` matrix, giving an `M x P` matrix;
+    /// mismatched inner dimensions are rejected at compile time.
+    ///
     /// # Examples
     ///
     /// ```
@@ -378,13 +415,17 @@ where
     /// let a = Matrix::<i32, 2, 2>::from([[1, 2], [3, 4]]);
     /// let b = Matrix::<i32, 2, 2>::from([[5, 6], [7, 8]]);
     /// let c = a * b;
-    /// assert_eq!(c.store, [[17, 23], [39, 53]]);
+    /// assert_eq!(c.store, [[19, 22], [43, 50]]);
+    ///
+    /// let d = Matrix::<i32, 2, 3>::from([[1, 0, 2], [0, 1, -1]]);
+    /// let e = Matrix::<i32, 3, 1>::from([[3], [4], [5]]);
+    /// assert_eq!((d * e).store, [[13], [-1]]);
     /// ```
-    fn mul(self, rhs: Matrix<T, N, N>) -> Self::Output {
+    fn mul(self, rhs: Matrix<T, N, P>) -> Self::Output {
         let mut result = Matrix::zero();
         for (i, row) in self.store.iter().enumerate() {
-            for (j, col) in rhs.store.iter().enumerate() {
-                for (e1, e2) in row.iter().zip(col.iter()) {
+            for (k, e1) in row.iter().enumerate() {
+                for (j, e2) in rhs.store[k].iter().enumerate() {
                     result.store[i][j] += *e1 * *e2;
                 }
             }
@@ -411,7 +452,7 @@ where
     /// assert_eq!(b.store, [[-1, 2], [3, -4]]);
     /// ````
     fn neg(self) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for row in result.store.iter_mut() {
             for elem in row.iter_mut() {
                 *elem = -*elem;
@@ -423,7 +464,7 @@ where
 
 impl<T, const M: usize, const N: usize> Display for Matrix<T, M, N>
 where
-    T: AddAssign + SubAssign + MulAssign + Copy + std::fmt::Display,
+    T: AddAssign + SubAssign + MulAssign + std::fmt::Display,
 {
     /// Formats the matrix for display.
     ///
@@ -455,7 +496,7 @@ where
             }
             write!(f, "]")?;
         }
-        write!(f, "\n")?;
+        writeln!(f)?;
         Ok(())
     }
 }
@@ -467,14 +508,23 @@ where
     /// Multiplies the matrix by a vector.
     ///
     /// # Arguments
-    /// * `vec` - The vector to multiply with the matrix.
+    /// * `vec` - The vector to multiply with the matrix, with one entry per column.
     ///
     /// # Returns
-    /// The resulting vector of the multiplication.
-    pub fn mul_vec(&mut self, vec: &Vector<T, N>) -> Vector<T, N> {
+    /// The resulting vector of the multiplication, with one entry per row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let mut a = Matrix::<i32, 2, 3>::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(a.mul_vec(&Vector::from([1, 0, -1])).store, [-2, -2]);
+    /// ```
+    pub fn mul_vec(&mut self, vec: &Vector<T, N>) -> Vector<T, M> {
         let mut result = Vector::zero();
-        for (idx, row) in self.store.iter_mut().enumerate() {
-            for (e1, e2) in row.iter_mut().zip(vec.store.iter()) {
+        for (idx, row) in self.store.iter().enumerate() {
+            for (e1, e2) in row.iter().zip(vec.store.iter()) {
                 result[idx] += *e1 * *e2;
             }
         }
@@ -484,14 +534,24 @@ where
     /// Multiplies the matrix by another matrix.
     ///
     /// # Arguments
-    /// * `mat` - The matrix to multiply with.
+    /// * `mat` - The `N x P` matrix to multiply with.
     ///
     /// # Returns
-    /// The resulting matrix of the multiplication.
-    pub fn mul_mat(&mut self, mat: &Matrix<T, M, N>) -> Matrix<T, M, N> {
+    /// The resulting `M x P` matrix of the multiplication.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let mut a = Matrix::<i32, 1, 3>::from([[1, 2, 3]]);
+    /// let b = Matrix::<i32, 3, 2>::from([[1, 0], [0, 1], [1, 1]]);
+    /// assert_eq!(a.mul_mat(&b).store, [[4, 5]]);
+    /// ```
+    pub fn mul_mat<const P: usize>(&mut self, mat: &Matrix<T, N, P>) -> Matrix<T, M, P> {
         let mut result = Matrix::zero();
         for i in 0..M {
-            for j in 0..N {
+            for j in 0..P {
                 for k in 0..N {
                     result[(i, j)] += self[(i, k)] * mat[(k, j)];
                 }
@@ -566,7 +626,7 @@ where
 /* ********************************************** */
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    T: Copy + Default + Num,
+    T: Clone + Default + Num,
 {
     /// Creates an identity matrix.
     ///
@@ -593,7 +653,7 @@ where
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    T: Copy + Default + Mul<Output = T> + PartialEq + Num + Div<Output = T> + Sub<Output = T>,
+    T: Clone + Num,
 {
     /// Converts a given matrix to its Reduced Row-Echelon Form (RREF).
     ///
@@ -642,9 +702,9 @@ where
     /// 1. **Starting Matrix**:
     ///
     ///    [
-    ///      [1, 2, 3],
-    ///      [4, 5, 6],
-    ///      [7, 8, 9]
+    ///    [1, 2, 3],
+    ///    [4, 5, 6],
+    ///    [7, 8, 9]
     ///    ]
     ///
     /// 2. **Make the Pivot of Row 1 (already 1)**:
@@ -708,50 +768,23 @@ where
     /// let b = a.row_echelon();
     /// // Check the result (approximate due to floating-point arithmetic)
     /// ```
+    ///
+    /// Every entry is computed with the arithmetic of `T`, so rational element types such as
+    /// `num::rational::Ratio<i64>` or `num::BigRational` give the exact reduced form:
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    /// use num::rational::Ratio;
+    ///
+    /// let r = |n: i64| Ratio::from_integer(n);
+    /// let a = Matrix::from([[r(8), r(5), r(-2)], [r(4), r(7), r(20)]]);
+    /// let rref = a.row_echelon();
+    /// assert_eq!(rref[(0, 2)], Ratio::new(-19, 6));
+    /// assert_eq!(rref[(1, 2)], Ratio::new(14, 3));
+    /// ```
     pub fn row_echelon(&self) -> Matrix<T, M, N> {
         let mut result = self.clone();
-        // let mut matrix_out = result.store;
-        let mut pivot = 0;
-        let row_count = M;
-        let column_count = N;
-
-        'outer: for r in 0..row_count {
-            if column_count <= pivot {
-                break;
-            }
-            let mut i = r;
-            while result[(i, pivot)] == T::default() {
-                i = i + 1;
-                if i == row_count {
-                    i = r;
-                    pivot = pivot + 1;
-                    if column_count == pivot {
-                        pivot = pivot - 1;
-                        break 'outer;
-                    }
-                }
-            }
-            for j in 0..row_count {
-                let temp = result[(r, j)];
-                result[(r, j)] = result[(i, j)];
-                result[(i, j)] = temp;
-            }
-            let divisor = result[(r, pivot)];
-            if divisor != T::default() {
-                for j in 0..column_count {
-                    result[(r, j)] = result[(r, j)] / divisor;
-                }
-            }
-            for j in 0..row_count {
-                if j != r {
-                    let hold = result[(j, pivot)];
-                    for k in 0..column_count {
-                        result[(j, k)] = result[(j, k)] - (hold * result[(r, k)]);
-                    }
-                }
-            }
-            pivot = pivot + 1;
-        }
+        elimination::row_echelon(result.store.as_flattened_mut(), M, N);
         result
     }
 }
@@ -760,9 +793,9 @@ where
 /*            Exercise 12 - Determinant            */
 /************************************************ */
 
-impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+impl<T, const N: usize> Matrix<T, N, N>
 where
-    T: Copy + Default + Mul + Num + Neg<Output = T> + AddAssign + Debug,
+    T: Clone + Num + PartialOrd + Neg<Output = T>,
 {
     /// Computes the determinant of the matrix.
     ///
@@ -810,9 +843,24 @@ where
     /// This indicates that the transformation represented by `A` scales areas by a factor of 2 and
     /// reverses their orientation.
     ///
-    /// # Panics
+    /// # Algorithm
+    ///
+    /// The determinant is computed by fraction-free Gaussian elimination (Bareiss) with partial
+    /// pivoting: at every step the row holding the largest-magnitude entry of the pivot column is
+    /// swapped into place, which keeps floating-point round-off under control. Because every
+    /// division performed by the Bareiss update is exact, the same routine gives exact results for
+    /// integer element types as long as the intermediate minors fit in `T`, and for rational types
+    /// such as `num::rational::Ratio<i64>` or `num::BigRational`, which need not be `Copy`.
     ///
-    /// Panics if the matrix size is larger than `4 x 4`.
+    /// The method only exists for square matrices, so calling it on a non-square shape is rejected
+    /// at compile time:
+    ///
+    /// ```compile_fail
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 3>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// a.determinant();
+    /// ```
     ///
     /// # Returns
     ///
@@ -827,46 +875,83 @@ where
     /// assert_eq!(a.determinant(), 0);
     /// ```
     pub fn determinant(&self) -> T {
-        match M {
-            1 => self[(0, 0)],
-            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
-            3 => self.determinant_3x3(),
-            4 => (0..4)
-                .map(|i| {
-                    let sign = if i % 2 == 0 { T::one() } else { -T::one() };
-                    let cofactor = self.get_cofactor(0, i);
-                    sign * self[(0, i)] * cofactor.determinant_3x3()
-                })
-                .fold(T::default(), |acc, x| acc + x),
-            _ => panic!("Determinant not implemented for matrices larger than 4x4"),
-        }
+        let mut a = self.clone();
+        elimination::determinant(a.store.as_flattened_mut(), N)
     }
+}
 
-    fn determinant_3x3(&self) -> T {
-        self[(0, 0)] * (self[(1, 1)] * self[(2, 2)] - self[(1, 2)] * self[(2, 1)])
-            - self[(0, 1)] * (self[(1, 0)] * self[(2, 2)] - self[(1, 2)] * self[(2, 0)])
-            + self[(0, 2)] * (self[(1, 0)] * self[(2, 1)] - self[(1, 1)] * self[(2, 0)])
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Clone + Num + PartialOrd + Neg<Output = T> + CheckedMul + CheckedSub + CheckedDiv,
+{
+    /// Computes the determinant of a signed integer matrix exactly, detecting overflow.
+    ///
+    /// This is the same fraction-free Bareiss elimination as [`Matrix::determinant`], but every
+    /// product, difference and quotient is checked. Each intermediate value is a minor of the
+    /// matrix, so they can grow far beyond the entries themselves; instead of wrapping around or
+    /// panicking, the computation stops and reports the overflow. Use
+    /// [`Matrix::determinant_bigint`] to get the value regardless of its size.
+    ///
+    /// The intermediate minors and the determinant of a matrix with non-negative entries can still
+    /// be negative, so the method requires a signed element type; unsigned matrices are rejected at
+    /// compile time rather than reporting a negative value as an overflow:
+    ///
+    /// ```compile_fail
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<u32, 2, 2>::from([[1, 2], [3, 4]]);
+    /// a.checked_determinant();
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(T)`, or [`LinalgError::Overflow`] if an intermediate minor or the determinant
+    /// does not fit in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    ///
+    /// let a = Matrix::<i32, 3, 3>::from([[2, -5, 0], [4, 3, 7], [-2, 3, 4]]);
+    /// assert_eq!(a.checked_determinant(), Ok(132));
+    ///
+    /// let b = Matrix::<i32, 2, 2>::from([[100_000, 1], [-1, 100_000]]);
+    /// assert_eq!(b.checked_determinant(), Err(LinalgError::Overflow));
+    /// ```
+    pub fn checked_determinant(&self) -> Result<T, LinalgError> {
+        let mut a = self.clone();
+        elimination::checked_determinant(a.store.as_flattened_mut(), N)
     }
+}
 
-    fn get_cofactor(&self, row: usize, col: usize) -> Matrix<T, 3, 3> {
-        let mut cofactor_matrix = Matrix::<T, 3, 3>::zero();
-        let mut row_index = 0;
-
-        for r in 0..M {
-            if r == row {
-                continue;
-            }
-            let mut col_index = 0;
-            for c in 0..N {
-                if c == col {
-                    continue;
-                }
-                cofactor_matrix[(row_index, col_index)] = self[(r, c)];
-                col_index += 1;
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Clone + Into<BigInt>,
+{
+    /// Computes the determinant exactly as an arbitrary-precision integer.
+    ///
+    /// The entries are converted to [`BigInt`] and eliminated by the Bareiss algorithm, so the
+    /// result is exact however large it is, at the cost of heap-allocated arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    /// use num::BigInt;
+    ///
+    /// let a = Matrix::<i64, 2, 2>::from([[i64::MAX, 1], [-1, i64::MAX]]);
+    /// let expected = BigInt::from(i64::MAX) * BigInt::from(i64::MAX) + 1;
+    /// assert_eq!(a.determinant_bigint(), expected);
+    /// ```
+    pub fn determinant_bigint(&self) -> BigInt {
+        let mut a = Matrix::<BigInt, N, N>::zero();
+        for i in 0..N {
+            for j in 0..N {
+                a[(i, j)] = self[(i, j)].clone().into();
             }
-            row_index += 1;
         }
-        cofactor_matrix
+        a.determinant()
     }
 }
 
@@ -876,49 +961,155 @@ where
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    T: Copy + Default + Mul + Num + Neg<Output = T> + AddAssign + Debug + Float,
+    T: Float + Default,
+{
+    /// Returns the tolerance used to decide whether a pivot is numerically zero.
+    ///
+    /// The tolerance is `max(M, N) * ε * max|aᵢⱼ|`, where `ε` is the machine epsilon of `T`, so it
+    /// scales with both the size and the magnitude of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, -4.0], [2.0, 3.0]]);
+    /// assert_eq!(a.default_tolerance(), 2.0 * 4.0 * f64::EPSILON);
+    /// ```
+    pub fn default_tolerance(&self) -> T {
+        let largest = self
+            .store
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        T::from(M.max(N)).unwrap() * T::epsilon() * largest
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default,
 {
     /// Calculates the inverse of the matrix.
     ///
-    /// This method supports matrices up to 3x3 in size.
+    /// The inverse is computed by Gauss-Jordan elimination with partial pivoting. A pivot whose
+    /// magnitude does not exceed [`Matrix::default_tolerance`] is treated as zero, in which case
+    /// the matrix is reported as singular. A matrix that passes this check can still be badly
+    /// conditioned; [`Matrix::condition_number_estimate`] tells how many digits of the inverse to
+    /// trust.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(Matrix)` if the inverse exists, or an `Err` with a descriptive message if not.
+    /// Returns `Ok(Matrix)` if the inverse exists, or [`LinalgError::Singular`] if not.
     ///
     /// # Examples
     ///
     /// ```
-    /// use mini_matrix::Matrix;
+    /// use mini_matrix::{LinalgError, Matrix};
     ///
     /// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
     /// let inv = a.inverse().unwrap();
     /// // Check the result (approximate due to floating-point arithmetic)
+    /// assert!((inv[(0, 0)] + 2.0).abs() < 1e-12);
+    ///
+    /// let singular = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.inverse(), Err(LinalgError::Singular));
     /// ```
-    pub fn inverse(&self) -> Result<Self, &'static str> {
-        if M != N {
-            return Err("Matrix must be square to calculate inverse");
-        }
+    pub fn inverse(&self) -> Result<Self, LinalgError> {
+        self.inverse_with_tolerance(self.default_tolerance())
+    }
 
-        let det = self.determinant();
+    /// Calculates the inverse of the matrix, treating any pivot whose magnitude is at most
+    /// `tolerance` as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 0.0], [0.0, 1e-9]]);
+    /// assert!(a.inverse_with_tolerance(1e-12).is_ok());
+    /// assert!(a.inverse_with_tolerance(1e-6).is_err());
+    /// ```
+    pub fn inverse_with_tolerance(&self, tolerance: T) -> Result<Self, LinalgError> {
+        let mut a = *self;
+        let mut inv = Self::identity();
+        elimination::invert(
+            a.store.as_flattened_mut(),
+            inv.store.as_flattened_mut(),
+            N,
+            tolerance,
+        )?;
+        Ok(inv)
+    }
+}
 
-        if det == T::zero() {
-            return Err("Matrix is singular and has no inverse");
-        }
+impl<I, const N: usize> Matrix<Ratio<I>, N, N>
+where
+    I: Clone + Integer,
+{
+    /// Calculates the inverse of the matrix in exact arithmetic.
+    ///
+    /// This is Gauss-Jordan elimination without any tolerance: a pivot is usable exactly when it
+    /// is non-zero. It needs exact division, so it is only available for rational element types
+    /// such as `num::rational::Ratio<i64>` or `num::BigRational`, for which the result is the
+    /// exact inverse. Integer division truncates, so integer matrices are rejected at compile
+    /// time; convert them to rationals first:
+    ///
+    /// ```compile_fail
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<i32, 2, 2>::from([[2, 1], [7, 4]]);
+    /// a.inverse_exact();
+    /// ```
+    ///
+    /// For floating-point matrices use [`Matrix::inverse`], which treats tiny pivots as zero.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)`, or [`LinalgError::Singular`] if the matrix has no inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    /// use num::rational::Ratio;
+    ///
+    /// let r = |n: i64, d: i64| Ratio::new(n, d);
+    /// let a = Matrix::from([[r(2, 1), r(1, 1)], [r(7, 1), r(4, 1)]]);
+    /// let inv = a.inverse_exact().unwrap();
+    /// assert_eq!(inv.store, [[r(4, 1), r(-1, 1)], [r(-7, 1), r(2, 1)]]);
+    ///
+    /// let b = Matrix::from([[r(1, 3), r(2, 3)], [r(1, 2), r(1, 1)]]);
+    /// assert_eq!(b.inverse_exact(), Err(LinalgError::Singular));
+    /// ```
+    pub fn inverse_exact(&self) -> Result<Self, LinalgError> {
+        let mut a = self.clone();
+        let mut inv = Self::identity();
 
-        let mut inv = Matrix::<T, N, M>::zero();
-        for i in 0..M {
+        for k in 0..N {
+            let pivot_row = (k..N)
+                .find(|&i| !a[(i, k)].is_zero())
+                .ok_or(LinalgError::Singular)?;
+            a.store.swap(k, pivot_row);
+            inv.store.swap(k, pivot_row);
+
+            let pivot = a[(k, k)].clone();
             for j in 0..N {
-                let coffactor = match M {
-                    2 => self.cofactor1x1(i, j).determinant(),
-                    3 => self.cofactor2x2(i, j).determinant(),
-                    _ => return Err("Inverse not implemented for matrices larger than 3x3"),
-                };
-                let base: i32 = -1;
-                inv[(i, j)] = (coffactor * T::from(base.pow((i + j) as u32)).unwrap()) / det;
+                a[(k, j)] = a[(k, j)].clone() / pivot.clone();
+                inv[(k, j)] = inv[(k, j)].clone() / pivot.clone();
+            }
+            for i in 0..N {
+                if i == k || a[(i, k)].is_zero() {
+                    continue;
+                }
+                let factor = a[(i, k)].clone();
+                for j in 0..N {
+                    a[(i, j)] = a[(i, j)].clone() - factor.clone() * a[(k, j)].clone();
+                    inv[(i, j)] = inv[(i, j)].clone() - factor.clone() * inv[(k, j)].clone();
+                }
             }
         }
-        let inv = inv.transpose();
         Ok(inv)
     }
 }
@@ -929,7 +1120,7 @@ where
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
 where
-    T: Copy + Default + Mul + Num + Neg<Output = T> + AddAssign + PartialEq,
+    T: Clone + Num,
 {
     /// Calculates the rank of the matrix.
     ///
@@ -944,20 +1135,6 @@ where
     /// assert_eq!(a.rank(), 2);
     /// ```
     pub fn rank(&self) -> usize {
-        let mut rank = M;
-        let row_echelon = self.row_echelon();
-        for i in 0..M {
-            let mut is_zero = true;
-            for j in 0..N {
-                if row_echelon[(i, j)] != T::default() {
-                    is_zero = false;
-                    break;
-                }
-            }
-            if is_zero {
-                rank -= 1;
-            }
-        }
-        rank
+        elimination::non_zero_rows(self.row_echelon().store.as_flattened(), M, N)
     }
 }
@@ -0,0 +1,204 @@
+//! # mini_matrix
+//!
+//! Matrix functions: the exponential, the principal square root and the principal logarithm.
+
+use num::Float;
+use std::ops::{AddAssign, MulAssign, SubAssign};
+
+use crate::{LinalgError, Matrix};
+
+/// The degree of the diagonal Padé approximant used by [`Matrix::exp`].
+const PADE_DEGREE: usize = 6;
+
+/// The iteration limit of the Denman–Beavers iteration in [`Matrix::sqrt`].
+const MAX_SQRT_ITERATIONS: usize = 100;
+
+/// The number of square roots [`Matrix::log`] takes at most to bring the matrix close to `I`.
+const MAX_LOG_SQUARE_ROOTS: usize = 64;
+
+/// The number of series terms [`Matrix::log`] sums at most; about ten suffice for `‖Z‖ <= 1/7`.
+const MAX_LOG_SERIES_TERMS: usize = 50;
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default + AddAssign + SubAssign + MulAssign,
+{
+    /// Computes the matrix exponential `e^A = Σ A^k / k!`.
+    ///
+    /// The exponential is evaluated by scaling and squaring: `A` is divided by a power of two
+    /// `2^s` until `‖A / 2^s‖∞ <= 1/2`, `e^(A / 2^s)` is approximated by the `[6/6]` Padé
+    /// approximant, which is accurate to about machine precision on that range, and the result
+    /// is squared `s` times. The solution of `x' = A * x` with `x(0) = x₀` is `x(t) = e^(tA) x₀`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)`, or [`LinalgError::Overflow`] if `A` has an infinite entry or an
+    /// entry of `e^A` does not fit in `T`. NaN entries propagate to the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    ///
+    /// // The generator of rotations by an angle θ.
+    /// let theta = 0.75_f64;
+    /// let generator = Matrix::from([[0.0, -theta], [theta, 0.0]]);
+    /// let rotation = generator.exp().unwrap();
+    /// assert!((rotation[(0, 0)] - theta.cos()).abs() < 1e-15);
+    /// assert!((rotation[(1, 0)] - theta.sin()).abs() < 1e-15);
+    ///
+    /// let huge = Matrix::<f64, 1, 1>::from([[1000.0]]);
+    /// assert_eq!(huge.exp(), Err(LinalgError::Overflow));
+    /// ```
+    pub fn exp(&self) -> Result<Self, LinalgError> {
+        let half = T::from(0.5).unwrap();
+        let two = T::from(2.0).unwrap();
+        let norm = self.norm_inf();
+        if norm.is_infinite() {
+            return Err(LinalgError::Overflow);
+        }
+        let squarings = if norm > half {
+            (norm.log2() - half.log2()).ceil().to_i32().unwrap()
+        } else {
+            0
+        };
+        // For norms near the largest float, 2^-s is subnormal or zero; scaling in two halves keeps
+        // both factors normal.
+        let x = *self * two.powi(-(squarings / 2)) * two.powi(-(squarings - squarings / 2));
+
+        // c_k = c_{k-1} * (q - k + 1) / (k * (2q - k + 1)), with the odd terms negated in the
+        // denominator.
+        let q = PADE_DEGREE;
+        let mut coefficient = T::one();
+        let mut power = Matrix::identity();
+        let mut numerator = Matrix::identity();
+        let mut denominator = Matrix::identity();
+        for k in 1..=q {
+            coefficient =
+                coefficient * T::from(q - k + 1).unwrap() / T::from(k * (2 * q - k + 1)).unwrap();
+            power = power * x;
+            let term = power * coefficient;
+            numerator = numerator + term;
+            denominator = if k % 2 == 0 {
+                denominator + term
+            } else {
+                denominator - term
+            };
+        }
+
+        let mut result = denominator.lu().solve_matrix(&numerator)?;
+        for _ in 0..squarings {
+            result = result * result;
+        }
+        if result.store.iter().flatten().any(|x| x.is_infinite()) {
+            return Err(LinalgError::Overflow);
+        }
+        Ok(result)
+    }
+
+    /// Computes the principal square root, the unique `X` with `X * X = A` whose eigenvalues
+    /// have positive real part.
+    ///
+    /// The Denman–Beavers iteration `Y ← (Y + Z⁻¹) / 2`, `Z ← (Z + Y⁻¹) / 2` starting from
+    /// `Y = A`, `Z = I` converges quadratically to `Y = A^(1/2)` and `Z = A^(-1/2)`. It needs
+    /// `A` to have no eigenvalues on the closed negative real axis, where the principal square
+    /// root is not defined or not real.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)`, [`LinalgError::Singular`] if an iterate becomes singular, as it
+    /// does for a singular `A` or for eigenvalues on the negative real axis, or
+    /// [`LinalgError::NoConvergence`] if the iteration does not settle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[33.0, 24.0], [48.0, 57.0]]);
+    /// let root = a.sqrt().unwrap();
+    /// assert!((root[(0, 0)] - 5.0).abs() < 1e-12);
+    /// assert!((root[(0, 1)] - 2.0).abs() < 1e-12);
+    /// assert!((root[(1, 0)] - 4.0).abs() < 1e-12);
+    /// assert!((root[(1, 1)] - 7.0).abs() < 1e-12);
+    ///
+    /// let negative = Matrix::<f64, 2, 2>::from([[-1.0, 0.0], [0.0, -1.0]]);
+    /// assert!(negative.sqrt().is_err());
+    /// ```
+    pub fn sqrt(&self) -> Result<Self, LinalgError> {
+        let half = T::from(0.5).unwrap();
+        let threshold = T::epsilon().sqrt();
+        let mut y = *self;
+        let mut z = Matrix::identity();
+        let mut settled = false;
+        for _ in 0..MAX_SQRT_ITERATIONS {
+            let y_inverse = y.lu().inverse()?;
+            let z_inverse = z.lu().inverse()?;
+            let next = (y + z_inverse) * half;
+            z = (z + y_inverse) * half;
+            let change = (next - y).frobenius_norm();
+            y = next;
+
+            // Convergence is quadratic, so one more step after the change drops to √ε brings
+            // the error down to working precision.
+            if settled {
+                return Ok(y);
+            }
+            settled = change <= threshold * y.frobenius_norm();
+        }
+        Err(LinalgError::NoConvergence)
+    }
+
+    /// Computes the principal logarithm, the unique `X` with `e^X = A` whose eigenvalues have
+    /// imaginary parts in `(-π, π)`.
+    ///
+    /// The logarithm is evaluated by inverse scaling and squaring: square roots are taken until
+    /// `‖A^(1/2^k) - I‖₁ <= 1/4`, the logarithm of that matrix is summed from the rapidly
+    /// converging series `log(B) = 2 Σ Z^(2j+1) / (2j + 1)` with `Z = (B - I)(B + I)⁻¹`, and the
+    /// result is multiplied by `2^k`. Like [`Matrix::sqrt`], it needs `A` to have no eigenvalues
+    /// on the closed negative real axis.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)`, or the error of the failing [`Matrix::sqrt`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [0.0, 3.0]]);
+    /// let log = a.log().unwrap();
+    /// assert!(log[(0, 0)].abs() < 1e-14);
+    /// assert!((log[(1, 1)] - 3f64.ln()).abs() < 1e-14);
+    /// assert!((log[(0, 1)] - 3f64.ln()).abs() < 1e-14);
+    /// ```
+    pub fn log(&self) -> Result<Self, LinalgError> {
+        let quarter = T::from(0.25).unwrap();
+        let identity = Matrix::identity();
+        let mut b = *self;
+        let mut square_roots = 0;
+        while (b - identity).norm_1() > quarter {
+            if square_roots == MAX_LOG_SQUARE_ROOTS {
+                return Err(LinalgError::NoConvergence);
+            }
+            b = b.sqrt()?;
+            square_roots += 1;
+        }
+
+        // B - I and B + I commute, so Z = (B + I)⁻¹ (B - I) as well.
+        let z = (b + identity).lu().solve_matrix(&(b - identity))?;
+        let z_squared = z * z;
+        let mut term = z;
+        let mut sum = z;
+        for j in 1..=MAX_LOG_SERIES_TERMS {
+            term = term * z_squared;
+            let contribution = term * (T::one() / T::from(2 * j + 1).unwrap());
+            sum = sum + contribution;
+            if contribution.frobenius_norm() <= T::epsilon() * sum.frobenius_norm() {
+                break;
+            }
+        }
+        Ok(sum * T::from(2.0).unwrap().powi(square_roots as i32 + 1))
+    }
+}
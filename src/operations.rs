@@ -43,9 +43,7 @@ use super::Vector;
 /// This function will panic if:
 /// - The number of vectors and scalars are not equal
 /// - The vector slice is empty
-
 // O(n)
-
 pub fn linear_combination<T, const N: usize>(
     vectors: &[Vector<T, N>],
     scalars: &[T],
@@ -80,8 +78,8 @@ where
 /// * `u` - The starting vector.
 /// * `v` - The ending vector.
 /// * `t` - A scalar parameter between 0.0 and 1.0 that determines the interpolation point.
-///         If `t` is 0.0, the result is `u`. If `t` is 1.0, the result is `v`.
-///         For values between 0.0 and 1.0, the result is a point between `u` and `v`.
+///   If `t` is 0.0, the result is `u`. If `t` is 1.0, the result is `v`.
+///   For values between 0.0 and 1.0, the result is a point between `u` and `v`.
 ///
 /// # Returns
 ///
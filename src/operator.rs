@@ -0,0 +1,190 @@
+//! # mini_matrix
+//!
+//! Linear operators: anything that can be multiplied by a vector.
+
+use num::Num;
+use std::ops::AddAssign;
+
+use crate::{CscMatrix, CsrMatrix, DMatrix, DVector, Matrix};
+
+/// A linear map `x ↦ A * x` from vectors of length `cols` to vectors of length `rows`.
+///
+/// The iterative solvers only ever multiply the system matrix by vectors, so they accept any
+/// `LinearOperator`: a dense [`Matrix`] or [`DMatrix`], a sparse [`CsrMatrix`] or [`CscMatrix`],
+/// or a closure wrapped in a [`FnOperator`] for matrices that are never stored explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{DVector, LinearOperator, Matrix};
+///
+/// let a = Matrix::from([[2.0, 1.0], [0.0, 3.0]]);
+/// assert_eq!(a.shape(), (2, 2));
+/// assert_eq!(a.apply(&DVector::from(vec![1.0, 1.0])).store, vec![3.0, 3.0]);
+/// assert_eq!(a.diagonal().store, vec![2.0, 3.0]);
+/// ```
+pub trait LinearOperator<T> {
+    /// Returns the shape of the operator as a tuple `(rows, columns)`.
+    fn shape(&self) -> (usize, usize);
+
+    /// Computes `A * x`.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the length of `x` differs from the number of columns.
+    fn apply(&self, x: &DVector<T>) -> DVector<T>;
+
+    /// Returns the main diagonal of the operator.
+    ///
+    /// The default implementation applies the operator to each standard basis vector, which
+    /// costs one product per diagonal entry; stored matrices read their diagonal directly.
+    fn diagonal(&self) -> DVector<T>
+    where
+        T: Copy + Default + Num,
+    {
+        let (rows, cols) = self.shape();
+        let mut diagonal = DVector::zero(rows.min(cols));
+        let mut unit = DVector::zero(cols);
+        for i in 0..diagonal.len() {
+            unit[i] = T::one();
+            diagonal[i] = self.apply(&unit)[i];
+            unit[i] = T::zero();
+        }
+        diagonal
+    }
+}
+
+/// A [`LinearOperator`] defined by a closure computing `A * x`.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{DVector, FnOperator, LinearOperator};
+///
+/// // The cyclic shift, without storing the permutation matrix.
+/// let shift = FnOperator::new(3, 3, |x: &DVector<i32>| {
+///     DVector::from(vec![x[2], x[0], x[1]])
+/// });
+/// assert_eq!(shift.apply(&DVector::from(vec![1, 2, 3])).store, vec![3, 1, 2]);
+/// assert_eq!(shift.diagonal().store, vec![0, 0, 0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FnOperator<F> {
+    rows: usize,
+    cols: usize,
+    function: F,
+}
+
+impl<F> FnOperator<F> {
+    /// Wraps `function`, which must map vectors of length `cols` to vectors of length `rows`.
+    pub fn new(rows: usize, cols: usize, function: F) -> Self {
+        Self {
+            rows,
+            cols,
+            function,
+        }
+    }
+}
+
+impl<T, F> LinearOperator<T> for FnOperator<F>
+where
+    F: Fn(&DVector<T>) -> DVector<T>,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        (self.function)(x)
+    }
+}
+
+impl<T, const M: usize, const N: usize> LinearOperator<T> for Matrix<T, M, N>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    fn shape(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        assert_eq!(x.len(), N, "vector length must match the number of columns");
+        let mut result = DVector::zero(M);
+        for (i, row) in self.store.iter().enumerate() {
+            for (&a, &b) in row.iter().zip(x.iter()) {
+                result[i] += a * b;
+            }
+        }
+        result
+    }
+
+    fn diagonal(&self) -> DVector<T> {
+        DVector::from((0..M.min(N)).map(|i| self[(i, i)]).collect::<Vec<_>>())
+    }
+}
+
+impl<T> LinearOperator<T> for DMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.size()
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        self.mul_vec(x).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn diagonal(&self) -> DVector<T> {
+        let (rows, cols) = self.size();
+        DVector::from(
+            (0..rows.min(cols))
+                .map(|i| self[(i, i)])
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.size()
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        self.mul_vec(x).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn diagonal(&self) -> DVector<T> {
+        let (rows, cols) = self.size();
+        DVector::from(
+            (0..rows.min(cols))
+                .map(|i| self.get(i, i))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    fn shape(&self) -> (usize, usize) {
+        self.size()
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        self.mul_vec(x).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn diagonal(&self) -> DVector<T> {
+        let (rows, cols) = self.size();
+        DVector::from(
+            (0..rows.min(cols))
+                .map(|i| self.get(i, i))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
@@ -0,0 +1,149 @@
+//! # mini_matrix
+//!
+//! Integer powers of square matrices by exponentiation by squaring.
+
+use num::{CheckedAdd, CheckedMul, Float, Num};
+use std::convert::Infallible;
+use std::ops::{AddAssign, MulAssign};
+
+use crate::{LinalgError, Matrix};
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Copy + Default + Num + AddAssign + MulAssign,
+{
+    /// Computes the power `A^k` by exponentiation by squaring.
+    ///
+    /// `A` is squared repeatedly and the squares selected by the binary digits of `exponent`
+    /// are multiplied together, so only `O(log k)` matrix products are needed instead of `k`.
+    /// `A⁰` is the identity, even for a singular `A`. Integer entries wrap around or panic on
+    /// overflow like the underlying arithmetic; use [`Matrix::checked_pow`] to detect it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// // Powers of the Fibonacci matrix hold consecutive Fibonacci numbers.
+    /// let fibonacci = Matrix::<u64, 2, 2>::from([[1, 1], [1, 0]]);
+    /// assert_eq!(fibonacci.pow(10).store, [[89, 55], [55, 34]]);
+    /// assert_eq!(fibonacci.pow(0), Matrix::identity());
+    /// ```
+    pub fn pow(&self, exponent: u64) -> Self {
+        match self.power_by_squaring(exponent, |&a, &b| Ok::<_, Infallible>(a * b)) {
+            Ok(power) => power,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Clone + Default + Num,
+{
+    /// Computes `A^k` from the binary digits of `exponent`, multiplying with `multiply`.
+    fn power_by_squaring<E, F>(&self, mut exponent: u64, multiply: F) -> Result<Self, E>
+    where
+        F: Fn(&Self, &Self) -> Result<Self, E>,
+    {
+        let mut result = Matrix::identity();
+        let mut square = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = multiply(&result, &square)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                square = multiply(&square, &square)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Clone + Default + Num + CheckedAdd + CheckedMul,
+{
+    /// Computes the power `A^k` of an integer matrix, detecting overflow.
+    ///
+    /// This is the same exponentiation by squaring as [`Matrix::pow`], but every product and
+    /// sum is checked. The repeated squares are intermediate results too, so for matrices with
+    /// entries of mixed sign an overflow may be reported even though `A^k` itself would fit.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)`, or [`LinalgError::Overflow`] if an entry does not fit in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix};
+    ///
+    /// let fibonacci = Matrix::<u64, 2, 2>::from([[1, 1], [1, 0]]);
+    /// // F(93) is the largest Fibonacci number that fits in a u64.
+    /// assert_eq!(fibonacci.checked_pow(92).unwrap()[(0, 0)], 12_200_160_415_121_876_738);
+    /// assert_eq!(fibonacci.checked_pow(93), Err(LinalgError::Overflow));
+    /// ```
+    pub fn checked_pow(&self, exponent: u64) -> Result<Self, LinalgError> {
+        self.power_by_squaring(exponent, |a, b| {
+            checked_product(a, b).ok_or(LinalgError::Overflow)
+        })
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default + AddAssign + MulAssign,
+{
+    /// Computes the power `A^k` for a signed exponent.
+    ///
+    /// A non-negative exponent is handled by [`Matrix::pow`]; a negative one raises
+    /// [`Matrix::inverse`] to the power `-k`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Matrix)`, or [`LinalgError::Singular`] if `exponent` is negative and the matrix
+    /// is singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[2.0, 0.0], [0.0, 4.0]]);
+    /// assert_eq!(a.powi(-2).unwrap().store, [[0.25, 0.0], [0.0, 0.0625]]);
+    /// assert_eq!(a.powi(3).unwrap().store, [[8.0, 0.0], [0.0, 64.0]]);
+    ///
+    /// let singular = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert!(singular.powi(-1).is_err());
+    /// ```
+    pub fn powi(&self, exponent: i64) -> Result<Self, LinalgError> {
+        if exponent >= 0 {
+            return Ok(self.pow(exponent.unsigned_abs()));
+        }
+        let inverse = self.inverse()?;
+        Ok(inverse.pow(exponent.unsigned_abs()))
+    }
+}
+
+/// Returns the product `a * b` of two square matrices, or `None` if an entry overflows.
+fn checked_product<T, const N: usize>(
+    a: &Matrix<T, N, N>,
+    b: &Matrix<T, N, N>,
+) -> Option<Matrix<T, N, N>>
+where
+    T: Clone + Default + Num + CheckedAdd + CheckedMul,
+{
+    let mut c = Matrix::zero();
+    for i in 0..N {
+        for j in 0..N {
+            let mut sum = T::zero();
+            for k in 0..N {
+                sum = sum.checked_add(&a[(i, k)].checked_mul(&b[(k, j)])?)?;
+            }
+            c[(i, j)] = sum;
+        }
+    }
+    Some(c)
+}
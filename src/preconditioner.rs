@@ -0,0 +1,218 @@
+//! # mini_matrix
+//!
+//! Preconditioners for the iterative solvers.
+
+use num::Float;
+use std::ops::AddAssign;
+
+use crate::{CsrMatrix, DVector, LinalgError, LinearOperator};
+
+/// An approximation `M ≈ A` whose systems `M * z = r` are cheap to solve.
+///
+/// Iterative solvers converge in fewer iterations on the preconditioned system, because `M⁻¹ * A`
+/// is closer to the identity than `A` is. [`SolverOptions::preconditioner`] attaches one to a
+/// solve.
+///
+/// [`SolverOptions::preconditioner`]: crate::SolverOptions::preconditioner
+pub trait Preconditioner<T> {
+    /// Solves `M * z = r` and returns `z`.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the length of `r` differs from the size of the preconditioner.
+    fn solve(&self, r: &DVector<T>) -> DVector<T>;
+}
+
+/// The Jacobi (diagonal) preconditioner `M = diag(A)`.
+///
+/// It is the cheapest preconditioner and removes the effect of badly scaled rows, but does
+/// little for matrices whose diagonal is already uniform.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{DVector, JacobiPreconditioner, Matrix, Preconditioner};
+///
+/// let a = Matrix::from([[4.0, 1.0], [1.0, 0.5]]);
+/// let jacobi = JacobiPreconditioner::new(&a).unwrap();
+/// assert_eq!(jacobi.solve(&DVector::from(vec![2.0, 2.0])).store, vec![0.5, 4.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JacobiPreconditioner<T> {
+    inverse_diagonal: DVector<T>,
+}
+
+impl<T> JacobiPreconditioner<T>
+where
+    T: Float + Default,
+{
+    /// Builds the preconditioner from the diagonal of `a`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(JacobiPreconditioner)`, [`LinalgError::NotSquare`] if `a` is not square, or
+    /// [`LinalgError::Singular`] if a diagonal entry is zero.
+    pub fn new<A>(a: &A) -> Result<Self, LinalgError>
+    where
+        A: LinearOperator<T> + ?Sized,
+    {
+        let (rows, cols) = a.shape();
+        if rows != cols {
+            return Err(LinalgError::NotSquare { rows, cols });
+        }
+        let mut inverse_diagonal = a.diagonal();
+        for value in inverse_diagonal.iter_mut() {
+            if *value == T::zero() {
+                return Err(LinalgError::Singular);
+            }
+            *value = value.recip();
+        }
+        Ok(Self { inverse_diagonal })
+    }
+}
+
+impl<T> Preconditioner<T> for JacobiPreconditioner<T>
+where
+    T: Float,
+{
+    fn solve(&self, r: &DVector<T>) -> DVector<T> {
+        assert_eq!(r.len(), self.inverse_diagonal.len());
+        DVector::from(
+            r.iter()
+                .zip(self.inverse_diagonal.iter())
+                .map(|(&x, &d)| x * d)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// The zero fill-in incomplete Cholesky preconditioner `M = L * Lᵀ`, IC(0).
+///
+/// `L` is computed like a Cholesky factor, but only at the positions where the lower triangle of
+/// the sparse matrix already has an entry, so it costs as much to store and apply as `A` itself.
+/// It is the usual preconditioner for conjugate gradients on discretised elliptic problems.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{CsrMatrix, DVector, IncompleteCholesky, Matrix, Preconditioner};
+///
+/// // A tridiagonal matrix has no fill-in, so IC(0) is its exact Cholesky factorization.
+/// let a = Matrix::<f64, 3, 3>::from([[4.0, 2.0, 0.0], [2.0, 5.0, 2.0], [0.0, 2.0, 5.0]]);
+/// let ic = IncompleteCholesky::new(&CsrMatrix::from(a)).unwrap();
+/// let z = ic.solve(&DVector::from(vec![6.0, 9.0, 7.0]));
+/// for (computed, expected) in z.iter().zip([1.0, 1.0, 1.0]) {
+///     assert!((computed - expected).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteCholesky<T> {
+    /// Row offsets of `L` in compressed sparse row form; the diagonal ends each row.
+    offsets: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> IncompleteCholesky<T>
+where
+    T: Float + Default + AddAssign,
+{
+    /// Computes the IC(0) factor of the symmetric matrix `a`.
+    ///
+    /// Only the lower triangle of `a` is read; the upper triangle is assumed to mirror it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(IncompleteCholesky)`, [`LinalgError::NotSquare`] if `a` is not square, or
+    /// [`LinalgError::NotPositiveDefinite`] if a pivot is not positive. The factorization can
+    /// break down this way even for some positive definite matrices, although not for
+    /// diagonally dominant ones or M-matrices.
+    pub fn new(a: &CsrMatrix<T>) -> Result<Self, LinalgError> {
+        let (rows, cols) = a.size();
+        if rows != cols {
+            return Err(LinalgError::NotSquare { rows, cols });
+        }
+        let (a_offsets, a_columns, a_values) = (a.row_offsets(), a.col_indices(), a.values());
+
+        let mut offsets = Vec::with_capacity(rows + 1);
+        let mut columns: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+        offsets.push(0);
+        for i in 0..rows {
+            let row_start = values.len();
+            for p in a_offsets[i]..a_offsets[i + 1] {
+                let k = a_columns[p];
+                if k > i {
+                    break;
+                }
+                // Subtract the dot product of the parts of rows `i` and `k` of `L` left of `k`.
+                let mut sum = a_values[p];
+                let (mut q, mut r) = (row_start, offsets[k]);
+                let row_k_end = if k < i { offsets[k + 1] - 1 } else { r };
+                while q < values.len() && r < row_k_end {
+                    match columns[q].cmp(&columns[r]) {
+                        std::cmp::Ordering::Less => q += 1,
+                        std::cmp::Ordering::Greater => r += 1,
+                        std::cmp::Ordering::Equal => {
+                            sum = sum - values[q] * values[r];
+                            q += 1;
+                            r += 1;
+                        }
+                    }
+                }
+                if k < i {
+                    columns.push(k);
+                    values.push(sum / values[offsets[k + 1] - 1]);
+                } else {
+                    for &l in values[row_start..].iter() {
+                        sum = sum - l * l;
+                    }
+                    if sum <= T::zero() || sum.is_nan() {
+                        return Err(LinalgError::NotPositiveDefinite);
+                    }
+                    columns.push(i);
+                    values.push(sum.sqrt());
+                }
+            }
+            if columns.last() != Some(&i) {
+                // The diagonal entry is missing, so the pivot is zero.
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            offsets.push(values.len());
+        }
+        Ok(Self {
+            offsets,
+            columns,
+            values,
+        })
+    }
+}
+
+impl<T> Preconditioner<T> for IncompleteCholesky<T>
+where
+    T: Float + Default,
+{
+    fn solve(&self, r: &DVector<T>) -> DVector<T> {
+        let n = self.offsets.len() - 1;
+        assert_eq!(r.len(), n);
+        // Forward substitution with `L`, then backward substitution with `Lᵀ` by columns.
+        let mut z = r.clone();
+        for i in 0..n {
+            let last = self.offsets[i + 1] - 1;
+            let mut sum = z[i];
+            for p in self.offsets[i]..last {
+                sum = sum - self.values[p] * z[self.columns[p]];
+            }
+            z[i] = sum / self.values[last];
+        }
+        for i in (0..n).rev() {
+            let last = self.offsets[i + 1] - 1;
+            z[i] = z[i] / self.values[last];
+            for p in self.offsets[i]..last {
+                let k = self.columns[p];
+                z[k] = z[k] - self.values[p] * z[i];
+            }
+        }
+        z
+    }
+}
@@ -0,0 +1,138 @@
+//! # mini_matrix
+//!
+//! Element-wise, Kronecker and outer products.
+
+use num::Num;
+
+use crate::{Matrix, Vector};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Clone + Num,
+{
+    /// Computes the Hadamard product `A ∘ B`, the entry-by-entry product of two matrices of the
+    /// same shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let b = Matrix::from([[7, 8, 9], [-1, 0, 2]]);
+    /// assert_eq!(a.hadamard(&b).store, [[7, 16, 27], [-4, 0, 12]]);
+    /// ```
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    /// Divides the matrix entry by entry by another matrix of the same shape.
+    ///
+    /// Division by a zero entry behaves like `/` on `T`: it panics for integers and gives an
+    /// infinity or NaN for floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 6.0], [-9.0, 0.0]]);
+    /// let b = Matrix::from([[4.0, 3.0], [3.0, 5.0]]);
+    /// assert_eq!(a.elementwise_div(&b).store, [[0.25, 2.0], [-3.0, 0.0]]);
+    /// ```
+    pub fn elementwise_div(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    /// Computes the Kronecker product `A ⊗ B` of an `M x N` and a `P x Q` matrix.
+    ///
+    /// The result is the `MP x NQ` block matrix whose block `(i, j)` is `a_ij * B`. Stable Rust
+    /// cannot spell the shape `{M * P} x {N * Q}` in a signature, so it is given by the type
+    /// parameters `R` and `S`, usually inferred from the binding; any other shape is rejected at
+    /// compile time:
+    ///
+    /// ```compile_fail
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[1, 0, 1]]);
+    /// let c: Matrix<i32, 2, 4> = a.kronecker(&b);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[0, 5], [6, 7]]);
+    /// let c: Matrix<i32, 4, 4> = a.kronecker(&b);
+    /// assert_eq!(
+    ///     c.store,
+    ///     [
+    ///         [0, 5, 0, 10],
+    ///         [6, 7, 12, 14],
+    ///         [0, 15, 0, 20],
+    ///         [18, 21, 24, 28],
+    ///     ]
+    /// );
+    /// ```
+    pub fn kronecker<const P: usize, const Q: usize, const R: usize, const S: usize>(
+        &self,
+        other: &Matrix<T, P, Q>,
+    ) -> Matrix<T, R, S> {
+        const {
+            assert!(
+                R == M * P && S == N * Q,
+                "Kronecker product of M x N and P x Q matrices is MP x NQ"
+            )
+        };
+
+        Matrix {
+            store: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    self[(i / P, j / Q)].clone() * other[(i % P, j % Q)].clone()
+                })
+            }),
+        }
+    }
+
+    /// Combines the entries of two matrices of the same shape with `f`.
+    fn zip_with<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: Fn(T, T) -> T,
+    {
+        Matrix {
+            store: std::array::from_fn(|i| {
+                std::array::from_fn(|j| f(self[(i, j)].clone(), other[(i, j)].clone()))
+            }),
+        }
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Clone + Num,
+{
+    /// Computes the outer product `u * vᵀ`, the `N x K` matrix with entries `u_i * v_j`.
+    ///
+    /// Unlike the dot product, which `Vector`'s `*` computes, the vectors may have different
+    /// lengths. Every column of the result is a multiple of `u`, so it has rank at most one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Vector;
+    ///
+    /// let u = Vector::from([1, 2, 3]);
+    /// let v = Vector::from([4, -5]);
+    /// assert_eq!(u.outer(&v).store, [[4, -5], [8, -10], [12, -15]]);
+    /// ```
+    pub fn outer<const K: usize>(&self, other: &Vector<T, K>) -> Matrix<T, N, K> {
+        Matrix {
+            store: std::array::from_fn(|i| {
+                std::array::from_fn(|j| self[i].clone() * other[j].clone())
+            }),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! # mini_matrix
+//!
+//! QR decomposition by Householder reflections.
+
+use num::Float;
+use std::iter::Sum;
+
+use crate::{LinalgError, Matrix, Vector};
+
+/// The QR factorization `A = Q * R` of an `M x N` matrix with `M >= N`.
+///
+/// `Q` is orthogonal and `R` is upper triangular. The factorization is stored as the `N`
+/// Householder reflectors `Hₖ = I - 2 vₖ vₖᵀ` (with unit `vₖ`) whose product is `Q`, together with
+/// `R`. Both the full (`M x M` / `M x N`) and the thin (`M x N` / `N x N`) variants of the factors
+/// can be formed from it.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::Matrix;
+///
+/// let a = Matrix::<f64, 3, 2>::from([[3.0, 0.0], [4.0, 0.0], [0.0, 2.0]]);
+/// let qr = a.qr();
+///
+/// let r = qr.thin_r();
+/// assert!((r[(0, 0)].abs() - 5.0).abs() < 1e-12);
+/// assert!((r[(1, 1)].abs() - 2.0).abs() < 1e-12);
+/// assert_eq!(r[(1, 0)], 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Qr<T, const M: usize, const N: usize> {
+    reflectors: [Vector<T, M>; N],
+    r: Matrix<T, M, N>,
+    tolerance: T,
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Default + Sum,
+{
+    /// Computes the QR decomposition of the matrix using Householder reflections.
+    ///
+    /// For every column `k`, a reflector is chosen that maps the part of the column on and below
+    /// the diagonal onto a multiple of the first unit vector, zeroing the entries below `R[k][k]`.
+    /// The sign of each reflection is chosen to avoid cancellation, so the diagonal of `R` may
+    /// contain negative entries.
+    ///
+    /// The decomposition requires at least as many rows as columns; other shapes are rejected at
+    /// compile time:
+    ///
+    /// ```compile_fail
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 3>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// a.qr();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[0.0, 1.0], [1.0, 0.0]]);
+    /// let qr = a.qr();
+    /// let q = qr.q();
+    /// let r = qr.r();
+    /// for i in 0..2 {
+    ///     for j in 0..2 {
+    ///         let product: f64 = (0..2).map(|k| q[(i, k)] * r[(k, j)]).sum();
+    ///         assert!((product - a[(i, j)]).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn qr(&self) -> Qr<T, M, N> {
+        const {
+            assert!(
+                M >= N,
+                "QR decomposition requires at least as many rows as columns"
+            )
+        };
+
+        let two = T::one() + T::one();
+        let mut r = *self;
+        let mut reflectors = [Vector::zero(); N];
+
+        for (k, reflector) in reflectors.iter_mut().enumerate() {
+            let mut v = Vector::<T, M>::zero();
+            for i in k..M {
+                v[i] = r[(i, k)];
+            }
+            let alpha = if v[k] < T::zero() {
+                v.norm()
+            } else {
+                -v.norm()
+            };
+            v[k] = v[k] - alpha;
+
+            let length = v.norm();
+            if length == T::zero() {
+                continue;
+            }
+            v = v * (T::one() / length);
+
+            for j in k..N {
+                let mut column = Vector::<T, M>::zero();
+                for i in k..M {
+                    column[i] = r[(i, j)];
+                }
+                let projection = v.dot(&column) * two;
+                for i in k..M {
+                    r[(i, j)] = r[(i, j)] - v[i] * projection;
+                }
+            }
+            for i in k + 1..M {
+                r[(i, k)] = T::zero();
+            }
+            *reflector = v;
+        }
+
+        Qr {
+            reflectors,
+            r,
+            tolerance: self.default_tolerance(),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Qr<T, M, N>
+where
+    T: Float + Default + Sum,
+{
+    /// Applies `Qᵀ = Hₙ₋₁ ⋯ H₁ H₀` to a vector.
+    fn apply_qt(&self, x: &Vector<T, M>) -> Vector<T, M> {
+        let two = T::one() + T::one();
+        self.reflectors
+            .iter()
+            .fold(*x, |x, v| x - *v * (v.dot(&x) * two))
+    }
+
+    /// Applies `Q = H₀ H₁ ⋯ Hₙ₋₁` to a vector.
+    fn apply_q(&self, x: &Vector<T, M>) -> Vector<T, M> {
+        let two = T::one() + T::one();
+        self.reflectors
+            .iter()
+            .rev()
+            .fold(*x, |x, v| x - *v * (v.dot(&x) * two))
+    }
+
+    /// Returns the full `M x M` orthogonal factor `Q`.
+    pub fn q(&self) -> Matrix<T, M, M> {
+        let mut q = Matrix::zero();
+        for j in 0..M {
+            let mut e = Vector::zero();
+            e[j] = T::one();
+            let column = self.apply_q(&e);
+            for i in 0..M {
+                q[(i, j)] = column[i];
+            }
+        }
+        q
+    }
+
+    /// Returns the full `M x N` upper triangular factor `R`, whose last `M - N` rows are zero.
+    pub fn r(&self) -> Matrix<T, M, N> {
+        self.r
+    }
+
+    /// Returns the thin `M x N` factor `Q₁` made of the first `N` columns of `Q`.
+    ///
+    /// Its columns form an orthonormal basis of the column space of a full-rank `A`, and
+    /// `A = Q₁ * R₁`.
+    pub fn thin_q(&self) -> Matrix<T, M, N> {
+        let mut q = Matrix::zero();
+        for j in 0..N {
+            let mut e = Vector::zero();
+            e[j] = T::one();
+            let column = self.apply_q(&e);
+            for i in 0..M {
+                q[(i, j)] = column[i];
+            }
+        }
+        q
+    }
+
+    /// Returns the thin `N x N` upper triangular factor `R₁` made of the first `N` rows of `R`.
+    pub fn thin_r(&self) -> Matrix<T, N, N> {
+        let mut r = Matrix::zero();
+        for i in 0..N {
+            for j in i..N {
+                r[(i, j)] = self.r[(i, j)];
+            }
+        }
+        r
+    }
+
+    /// Solves the least-squares problem `min ‖A * x - b‖₂` for a matrix with full column rank.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Vector)` with the minimizer, or [`LinalgError::Singular`] if a diagonal entry
+    /// of `R` is zero within [`Matrix::default_tolerance`], i.e. `A` is rank deficient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// // Fit y = c₀ + c₁ t through (0, 1), (1, 3), (2, 5).
+    /// let a = Matrix::<f64, 3, 2>::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+    /// let c = a.qr().solve(&Vector::from([1.0, 3.0, 5.0])).unwrap();
+    /// assert!((c[0] - 1.0).abs() < 1e-12);
+    /// assert!((c[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn solve(&self, b: &Vector<T, M>) -> Result<Vector<T, N>, LinalgError> {
+        if (0..N).any(|i| self.r[(i, i)].abs() <= self.tolerance) {
+            return Err(LinalgError::Singular);
+        }
+
+        let y = self.apply_qt(b);
+        let mut x = Vector::zero();
+        for i in (0..N).rev() {
+            let mut sum = y[i];
+            for j in i + 1..N {
+                sum = sum - self.r[(i, j)] * x[j];
+            }
+            x[i] = sum / self.r[(i, i)];
+        }
+        Ok(x)
+    }
+}
@@ -0,0 +1,599 @@
+//! # mini_matrix
+//!
+//! Sparse matrices in coordinate (COO), compressed sparse row (CSR) and compressed sparse column
+//! (CSC) formats.
+
+use num::Num;
+use std::ops::AddAssign;
+
+use crate::{DMatrix, DVector, LinalgError, Matrix};
+
+/// A sparse matrix in coordinate format, used to assemble CSR and CSC matrices.
+///
+/// Entries are stored as unordered `(row, column, value)` triplets. Pushing the same position
+/// more than once is allowed; the values are summed when the matrix is compressed, which is what
+/// finite-element and finite-difference assembly needs.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::CooMatrix;
+///
+/// let mut coo = CooMatrix::new(3, 3);
+/// coo.push(0, 0, 2.0).unwrap();
+/// coo.push(2, 1, -1.0).unwrap();
+/// coo.push(0, 0, 1.0).unwrap();
+/// assert!(coo.push(3, 0, 1.0).is_err());
+///
+/// let csr = coo.to_csr();
+/// assert_eq!(csr.nnz(), 2);
+/// assert_eq!(csr.get(0, 0), 3.0);
+/// assert_eq!(csr.get(1, 1), 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    row_indices: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+/// A sparse matrix in compressed sparse row format.
+///
+/// The column indices and values of row `i` are stored at positions
+/// `row_offsets[i]..row_offsets[i + 1]`, sorted by column and without duplicates. Row access and
+/// matrix-vector products are fast in this format.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{CsrMatrix, DVector, Matrix};
+///
+/// let a = CsrMatrix::from(Matrix::from([[1.0, 0.0, 2.0], [0.0, 3.0, 0.0]]));
+/// assert_eq!(a.row_offsets(), &[0, 2, 3]);
+/// assert_eq!(a.col_indices(), &[0, 2, 1]);
+/// assert_eq!(a.values(), &[1.0, 2.0, 3.0]);
+///
+/// let y = a.mul_vec(&DVector::from(vec![1.0, 1.0, 1.0])).unwrap();
+/// assert_eq!(y.store, vec![3.0, 3.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+/// A sparse matrix in compressed sparse column format.
+///
+/// The row indices and values of column `j` are stored at positions
+/// `col_offsets[j]..col_offsets[j + 1]`, sorted by row and without duplicates. Column access is
+/// fast in this format, which direct sparse solvers favour.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{CscMatrix, Matrix};
+///
+/// let a = CscMatrix::from(Matrix::from([[1.0, 0.0, 2.0], [0.0, 3.0, 0.0]]));
+/// assert_eq!(a.col_offsets(), &[0, 1, 2, 3]);
+/// assert_eq!(a.row_indices(), &[0, 1, 0]);
+/// assert_eq!(a.values(), &[1.0, 3.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    rows: usize,
+    cols: usize,
+    col_offsets: Vec<usize>,
+    row_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+/// Sorts triplets into compressed form along the `major` axis, summing duplicate positions.
+///
+/// Returns the offsets (one per major index, plus one), the minor indices and the values.
+fn compress<T>(
+    major_count: usize,
+    majors: &[usize],
+    minors: &[usize],
+    values: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Copy + AddAssign,
+{
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|&k| (majors[k], minors[k]));
+
+    let mut offsets = vec![0; major_count + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(values.len());
+    let mut compressed: Vec<T> = Vec::with_capacity(values.len());
+    let mut previous = None;
+    for &k in order.iter() {
+        let position = (majors[k], minors[k]);
+        if previous == Some(position) {
+            *compressed.last_mut().unwrap() += values[k];
+        } else {
+            offsets[position.0 + 1] += 1;
+            indices.push(position.1);
+            compressed.push(values[k]);
+            previous = Some(position);
+        }
+    }
+    for i in 0..major_count {
+        offsets[i + 1] += offsets[i];
+    }
+    (offsets, indices, compressed)
+}
+
+/* ************************************************* */
+/*                     CooMatrix                     */
+/* ************************************************* */
+
+impl<T> CooMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    /// Creates an empty `rows x cols` matrix.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            row_indices: Vec::new(),
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Adds `value` to the entry at `(row, col)`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())`, or [`LinalgError::IndexOutOfBounds`] if the position lies outside the
+    /// matrix.
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<(), LinalgError> {
+        if row >= self.rows || col >= self.cols {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: (row, col),
+                shape: (self.rows, self.cols),
+            });
+        }
+        self.row_indices.push(row);
+        self.col_indices.push(col);
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// Returns the dimensions of the matrix as a tuple `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of stored triplets, counting duplicates separately.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Compresses the matrix into CSR format, summing duplicate entries.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (row_offsets, col_indices, values) = compress(
+            self.rows,
+            &self.row_indices,
+            &self.col_indices,
+            &self.values,
+        );
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Compresses the matrix into CSC format, summing duplicate entries.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (col_offsets, row_indices, values) = compress(
+            self.cols,
+            &self.col_indices,
+            &self.row_indices,
+            &self.values,
+        );
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    /// Converts the matrix to a dense `DMatrix`, summing duplicate entries.
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut dense = DMatrix::zero(self.rows, self.cols);
+        for k in 0..self.values.len() {
+            dense[(self.row_indices[k], self.col_indices[k])] += self.values[k];
+        }
+        dense
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for CooMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    /// Collects the non-zero entries of a dense matrix, in row-major order.
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        let mut coo = Self::new(M, N);
+        for i in 0..M {
+            for j in 0..N {
+                if matrix[(i, j)] != T::zero() {
+                    coo.row_indices.push(i);
+                    coo.col_indices.push(j);
+                    coo.values.push(matrix[(i, j)]);
+                }
+            }
+        }
+        coo
+    }
+}
+
+/* ************************************************* */
+/*                     CsrMatrix                     */
+/* ************************************************* */
+
+impl<T> CsrMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    /// Returns the dimensions of the matrix as a tuple `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the row offsets, of length `rows + 1`.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    /// Returns the column index of every stored entry.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    /// Returns the value of every stored entry.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the entry at `(row, col)`, which is zero if it is not stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position lies outside the matrix.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "{}",
+            LinalgError::IndexOutOfBounds {
+                index: (row, col),
+                shape: (self.rows, self.cols)
+            }
+        );
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Returns the transpose, also in CSR format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{CsrMatrix, Matrix};
+    ///
+    /// let a = CsrMatrix::from(Matrix::from([[1, 0, 2], [0, 3, 0]]));
+    /// let t = a.transpose();
+    /// assert_eq!(t.size(), (3, 2));
+    /// assert_eq!(t.get(2, 0), 2);
+    /// ```
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        // The CSC arrays of a matrix are the CSR arrays of its transpose.
+        let csc = self.to_csc();
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets: csc.col_offsets,
+            col_indices: csc.row_indices,
+            values: csc.values,
+        }
+    }
+
+    /// Converts the matrix to CSC format.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let row_indices: Vec<usize> = (0..self.rows)
+            .flat_map(|i| std::iter::repeat_n(i, self.row_offsets[i + 1] - self.row_offsets[i]))
+            .collect();
+        let (col_offsets, row_indices, values) =
+            compress(self.cols, &self.col_indices, &row_indices, &self.values);
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    /// Converts the matrix to a dense `DMatrix`.
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut dense = DMatrix::zero(self.rows, self.cols);
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                dense[(i, self.col_indices[k])] = self.values[k];
+            }
+        }
+        dense
+    }
+
+    /// Multiplies the matrix by a dense vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DVector)` with one entry per row, or [`LinalgError::DimensionMismatch`] if the
+    /// length of the vector differs from the number of columns.
+    pub fn mul_vec(&self, vec: &DVector<T>) -> Result<DVector<T>, LinalgError> {
+        if vec.len() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (vec.len(), 1),
+            });
+        }
+        let mut result = DVector::zero(self.rows);
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                result[i] += self.values[k] * vec[self.col_indices[k]];
+            }
+        }
+        Ok(result)
+    }
+
+    /// Multiplies the matrix by a dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DMatrix)` of shape `(self.rows, mat.cols)`, or
+    /// [`LinalgError::DimensionMismatch`] if `mat` does not have as many rows as `self` has
+    /// columns.
+    pub fn mul_dense(&self, mat: &DMatrix<T>) -> Result<DMatrix<T>, LinalgError> {
+        let (rows, cols) = mat.size();
+        if rows != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, cols),
+                found: (rows, cols),
+            });
+        }
+        let mut result = DMatrix::zero(self.rows, cols);
+        for i in 0..self.rows {
+            for k in self.row_offsets[i]..self.row_offsets[i + 1] {
+                let (value, row) = (self.values[k], mat.row(self.col_indices[k]));
+                for (j, &x) in row.iter().enumerate() {
+                    result[(i, j)] += value * x;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for CsrMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    /// Compresses the non-zero entries of a dense matrix.
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        CooMatrix::from(matrix).to_csr()
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<CsrMatrix<T>> for Matrix<T, M, N>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    type Error = LinalgError;
+
+    /// Expands a CSR matrix into a dense matrix.
+    ///
+    /// Fails with [`LinalgError::DimensionMismatch`] unless the shape is exactly `M x N`.
+    fn try_from(matrix: CsrMatrix<T>) -> Result<Self, Self::Error> {
+        Matrix::try_from(matrix.to_dense())
+    }
+}
+
+/* ************************************************* */
+/*                     CscMatrix                     */
+/* ************************************************* */
+
+impl<T> CscMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    /// Returns the dimensions of the matrix as a tuple `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the column offsets, of length `cols + 1`.
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.col_offsets
+    }
+
+    /// Returns the row index of every stored entry.
+    pub fn row_indices(&self) -> &[usize] {
+        &self.row_indices
+    }
+
+    /// Returns the value of every stored entry.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the entry at `(row, col)`, which is zero if it is not stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position lies outside the matrix.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "{}",
+            LinalgError::IndexOutOfBounds {
+                index: (row, col),
+                shape: (self.rows, self.cols)
+            }
+        );
+        let range = self.col_offsets[col]..self.col_offsets[col + 1];
+        match self.row_indices[range.clone()].binary_search(&row) {
+            Ok(k) => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Returns the transpose, also in CSC format.
+    pub fn transpose(&self) -> CscMatrix<T> {
+        // The CSR arrays of a matrix are the CSC arrays of its transpose.
+        let csr = self.to_csr();
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            col_offsets: csr.row_offsets,
+            row_indices: csr.col_indices,
+            values: csr.values,
+        }
+    }
+
+    /// Converts the matrix to CSR format.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let col_indices: Vec<usize> = (0..self.cols)
+            .flat_map(|j| std::iter::repeat_n(j, self.col_offsets[j + 1] - self.col_offsets[j]))
+            .collect();
+        let (row_offsets, col_indices, values) =
+            compress(self.rows, &self.row_indices, &col_indices, &self.values);
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Converts the matrix to a dense `DMatrix`.
+    pub fn to_dense(&self) -> DMatrix<T> {
+        let mut dense = DMatrix::zero(self.rows, self.cols);
+        for j in 0..self.cols {
+            for k in self.col_offsets[j]..self.col_offsets[j + 1] {
+                dense[(self.row_indices[k], j)] = self.values[k];
+            }
+        }
+        dense
+    }
+
+    /// Multiplies the matrix by a dense vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DVector)` with one entry per row, or [`LinalgError::DimensionMismatch`] if the
+    /// length of the vector differs from the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{CscMatrix, DVector, Matrix};
+    ///
+    /// let a = CscMatrix::from(Matrix::from([[1, 0, 2], [0, 3, 0]]));
+    /// let y = a.mul_vec(&DVector::from(vec![1, 2, 3])).unwrap();
+    /// assert_eq!(y.store, vec![7, 6]);
+    /// ```
+    pub fn mul_vec(&self, vec: &DVector<T>) -> Result<DVector<T>, LinalgError> {
+        if vec.len() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (vec.len(), 1),
+            });
+        }
+        let mut result = DVector::zero(self.rows);
+        for j in 0..self.cols {
+            for k in self.col_offsets[j]..self.col_offsets[j + 1] {
+                result[self.row_indices[k]] += self.values[k] * vec[j];
+            }
+        }
+        Ok(result)
+    }
+
+    /// Multiplies the matrix by a dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(DMatrix)` of shape `(self.rows, mat.cols)`, or
+    /// [`LinalgError::DimensionMismatch`] if `mat` does not have as many rows as `self` has
+    /// columns.
+    pub fn mul_dense(&self, mat: &DMatrix<T>) -> Result<DMatrix<T>, LinalgError> {
+        let (rows, cols) = mat.size();
+        if rows != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, cols),
+                found: (rows, cols),
+            });
+        }
+        let mut result = DMatrix::zero(self.rows, cols);
+        for k in 0..self.cols {
+            let row = mat.row(k);
+            for p in self.col_offsets[k]..self.col_offsets[k + 1] {
+                let (i, value) = (self.row_indices[p], self.values[p]);
+                for (j, &x) in row.iter().enumerate() {
+                    result[(i, j)] += value * x;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for CscMatrix<T>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    /// Compresses the non-zero entries of a dense matrix.
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        CooMatrix::from(matrix).to_csc()
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<CscMatrix<T>> for Matrix<T, M, N>
+where
+    T: Copy + Default + Num + AddAssign,
+{
+    type Error = LinalgError;
+
+    /// Expands a CSC matrix into a dense matrix.
+    ///
+    /// Fails with [`LinalgError::DimensionMismatch`] unless the shape is exactly `M x N`.
+    fn try_from(matrix: CscMatrix<T>) -> Result<Self, Self::Error> {
+        Matrix::try_from(matrix.to_dense())
+    }
+}
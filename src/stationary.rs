@@ -0,0 +1,261 @@
+//! # mini_matrix
+//!
+//! Classical stationary iterative methods: Jacobi, Gauss–Seidel and successive over-relaxation.
+
+use num::Float;
+use std::iter::Sum;
+
+use crate::{LinalgError, Matrix, Vector};
+
+/// The outcome of a Jacobi, Gauss–Seidel or SOR solve of an `N x N` system.
+///
+/// Besides the solution, it records the residual norm `‖b - A * x‖` of every iterate and whether
+/// the matrix is strictly diagonally dominant. Dominance guarantees that Jacobi and Gauss–Seidel
+/// converge (and SOR for `0 < ω <= 1`); when it is missing the methods may still converge, but
+/// may also stagnate or diverge, so [`StationarySolution::diagonally_dominant`] returning `false`
+/// is a warning to check [`StationarySolution::converged`].
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::{Matrix, Vector};
+///
+/// let a = Matrix::<f64, 2, 2>::from([[4.0, 1.0], [2.0, 5.0]]);
+/// let b = Vector::from([5.0, 7.0]);
+/// let result = a.gauss_seidel(&b, 1e-12, 100).unwrap();
+/// assert!(result.diagonally_dominant());
+/// assert!(result.converged());
+/// assert!((result.solution()[0] - 1.0).abs() < 1e-12);
+/// assert!((result.solution()[1] - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StationarySolution<T, const N: usize> {
+    solution: Vector<T, N>,
+    iterations: usize,
+    residual_history: Vec<T>,
+    converged: bool,
+    diagonally_dominant: bool,
+}
+
+impl<T, const N: usize> StationarySolution<T, N>
+where
+    T: Float,
+{
+    /// Returns the approximate solution `x`.
+    pub fn solution(&self) -> Vector<T, N> {
+        self.solution
+    }
+
+    /// Returns the number of iterations performed.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Returns the residual norm `‖b - A * x‖` of the initial guess and of every iterate.
+    pub fn residual_history(&self) -> &[T] {
+        &self.residual_history
+    }
+
+    /// Returns the final residual norm `‖b - A * x‖`.
+    pub fn residual_norm(&self) -> T {
+        *self.residual_history.last().unwrap()
+    }
+
+    /// Returns whether the residual reached the requested tolerance.
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /// Returns whether the matrix is strictly diagonally dominant by rows, which guarantees
+    /// convergence of the Jacobi and Gauss–Seidel iterations.
+    pub fn diagonally_dominant(&self) -> bool {
+        self.diagonally_dominant
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default + Sum,
+{
+    /// Returns whether the matrix is strictly diagonally dominant by rows, that is whether
+    /// `|a_ii| > Σ_{j≠i} |a_ij|` for every row `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[3.0, -2.0], [1.0, 2.0]]);
+    /// assert!(a.is_diagonally_dominant());
+    /// let b = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert!(!b.is_diagonally_dominant());
+    /// ```
+    pub fn is_diagonally_dominant(&self) -> bool {
+        (0..N).all(|i| {
+            let off_diagonal: T = (0..N).filter(|&j| j != i).map(|j| self[(i, j)].abs()).sum();
+            self[(i, i)].abs() > off_diagonal
+        })
+    }
+
+    /// Solves `A * x = b` by the Jacobi method, starting from `x = 0`.
+    ///
+    /// Every component of the next iterate is computed from the previous iterate alone:
+    /// `x_i ← (b_i - Σ_{j≠i} a_ij * x_j) / a_ii`. The iteration stops once
+    /// `‖b - A * x‖ <= tolerance * ‖b‖` or after `max_iterations` iterations.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(StationarySolution)` whether or not the iteration converged, or
+    /// [`LinalgError::Singular`] if a diagonal entry is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 3, 3>::from([[4.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 4.0]]);
+    /// let b = Vector::from([3.0, 2.0, 3.0]);
+    /// let result = a.jacobi(&b, 1e-10, 100).unwrap();
+    /// assert!(result.converged());
+    /// for &x in result.solution().iter() {
+    ///     assert!((x - 1.0).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn jacobi(
+        &self,
+        b: &Vector<T, N>,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Result<StationarySolution<T, N>, LinalgError> {
+        self.stationary_iteration(b, tolerance, max_iterations, |x| {
+            let previous = *x;
+            for i in 0..N {
+                let mut sum = b[i];
+                for j in (0..N).filter(|&j| j != i) {
+                    sum = sum - self[(i, j)] * previous[j];
+                }
+                x[i] = sum / self[(i, i)];
+            }
+        })
+    }
+
+    /// Solves `A * x = b` by the Gauss–Seidel method, starting from `x = 0`.
+    ///
+    /// Unlike the Jacobi method, each component is updated in place, so the new values of
+    /// `x_0, ..., x_{i-1}` are already used when computing `x_i`. This typically halves the
+    /// number of iterations, and it converges for every symmetric positive definite matrix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(StationarySolution)` whether or not the iteration converged, or
+    /// [`LinalgError::Singular`] if a diagonal entry is zero.
+    pub fn gauss_seidel(
+        &self,
+        b: &Vector<T, N>,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Result<StationarySolution<T, N>, LinalgError> {
+        self.sor(b, T::one(), tolerance, max_iterations)
+    }
+
+    /// Solves `A * x = b` by successive over-relaxation, starting from `x = 0`.
+    ///
+    /// Each Gauss–Seidel update is extrapolated by the relaxation factor `omega`:
+    /// `x_i ← (1 - ω) * x_i + ω * x_i^GS`. `ω = 1` is Gauss–Seidel; a well-chosen `1 < ω < 2`
+    /// can need far fewer iterations on discretised elliptic problems.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(StationarySolution)` whether or not the iteration converged,
+    /// [`LinalgError::InvalidRelaxation`] unless `0 < omega < 2`, outside of which the method
+    /// cannot converge, or [`LinalgError::Singular`] if a diagonal entry is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{LinalgError, Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 3, 3>::from([[4.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 4.0]]);
+    /// let b = Vector::from([3.0, 2.0, 3.0]);
+    /// let sor = a.sor(&b, 1.1, 1e-10, 100).unwrap();
+    /// let gauss_seidel = a.gauss_seidel(&b, 1e-10, 100).unwrap();
+    /// assert!(sor.converged());
+    /// assert!(sor.iterations() <= gauss_seidel.iterations());
+    /// assert_eq!(
+    ///     a.sor(&b, 2.0, 1e-10, 100),
+    ///     Err(LinalgError::InvalidRelaxation { omega: 2.0 })
+    /// );
+    /// ```
+    pub fn sor(
+        &self,
+        b: &Vector<T, N>,
+        omega: T,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Result<StationarySolution<T, N>, LinalgError> {
+        let two = T::one() + T::one();
+        if !(omega > T::zero() && omega < two) {
+            return Err(LinalgError::InvalidRelaxation {
+                omega: omega.to_f64().unwrap_or(f64::NAN),
+            });
+        }
+        self.stationary_iteration(b, tolerance, max_iterations, |x| {
+            for i in 0..N {
+                let mut sum = b[i];
+                for j in (0..N).filter(|&j| j != i) {
+                    sum = sum - self[(i, j)] * x[j];
+                }
+                x[i] = (T::one() - omega) * x[i] + omega * sum / self[(i, i)];
+            }
+        })
+    }
+
+    /// Runs `step` from `x = 0` until the relative residual drops to `tolerance`, the iteration
+    /// limit is reached or the iterates stop being finite.
+    fn stationary_iteration<F>(
+        &self,
+        b: &Vector<T, N>,
+        tolerance: T,
+        max_iterations: usize,
+        mut step: F,
+    ) -> Result<StationarySolution<T, N>, LinalgError>
+    where
+        F: FnMut(&mut Vector<T, N>),
+    {
+        if (0..N).any(|i| self[(i, i)] == T::zero()) {
+            return Err(LinalgError::Singular);
+        }
+        let residual_norm = |x: &Vector<T, N>| {
+            (0..N)
+                .map(|i| {
+                    let r = b[i] - (0..N).map(|j| self[(i, j)] * x[j]).sum::<T>();
+                    r * r
+                })
+                .sum::<T>()
+                .sqrt()
+        };
+        let threshold = tolerance * b.iter().map(|&x| x * x).sum::<T>().sqrt();
+
+        let mut x = Vector::zero();
+        let mut residual_history = vec![residual_norm(&x)];
+        let mut iterations = 0;
+        while iterations < max_iterations {
+            let current = *residual_history.last().unwrap();
+            if current <= threshold || !current.is_finite() {
+                break;
+            }
+            step(&mut x);
+            residual_history.push(residual_norm(&x));
+            iterations += 1;
+        }
+
+        let converged = *residual_history.last().unwrap() <= threshold;
+        Ok(StationarySolution {
+            solution: x,
+            iterations,
+            residual_history,
+            converged,
+            diagonally_dominant: self.is_diagonally_dominant(),
+        })
+    }
+}
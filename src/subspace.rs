@@ -0,0 +1,109 @@
+//! # mini_matrix
+//!
+//! Bases for the four fundamental subspaces, read off the reduced row echelon form.
+
+use num::Float;
+
+use crate::{Matrix, Vector};
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Default,
+{
+    /// Returns a basis of the null space, the vectors `x` with `A * x = 0`.
+    ///
+    /// There is one basis vector per free column of [`Matrix::rref`]: it has a one in that free
+    /// column, zeros in the other free columns, and the negated reduced entries of that column in
+    /// the pivot positions. The basis has `N - rank` vectors and is empty for full column rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 2, 3>::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+    /// let basis = a.null_space();
+    /// assert_eq!(
+    ///     basis,
+    ///     vec![Vector::from([-2.0, 1.0, 0.0]), Vector::from([-3.0, 0.0, 1.0])]
+    /// );
+    /// ```
+    pub fn null_space(&self) -> Vec<Vector<T, N>> {
+        let rref = self.rref();
+        let reduced = rref.reduced();
+        rref.free_columns()
+            .into_iter()
+            .map(|free| {
+                let mut x = Vector::zero();
+                x[free] = T::one();
+                for (k, &pivot) in rref.pivot_columns().iter().enumerate() {
+                    x[pivot] = -reduced[(k, free)];
+                }
+                x
+            })
+            .collect()
+    }
+
+    /// Returns a basis of the column space, the vectors `A * x` for all `x`.
+    ///
+    /// The basis consists of the columns of `A` itself at the pivot columns of [`Matrix::rref`],
+    /// so it has `rank` vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 2, 3>::from([[1.0, 2.0, 3.0], [2.0, 4.0, 7.0]]);
+    /// assert_eq!(
+    ///     a.column_space(),
+    ///     vec![Vector::from([1.0, 2.0]), Vector::from([3.0, 7.0])]
+    /// );
+    /// ```
+    pub fn column_space(&self) -> Vec<Vector<T, M>> {
+        self.rref()
+            .pivot_columns()
+            .iter()
+            .map(|&j| Vector::from(std::array::from_fn(|i| self[(i, j)])))
+            .collect()
+    }
+
+    /// Returns a basis of the row space, the span of the rows of `A`.
+    ///
+    /// Row operations do not change the row space, so the basis consists of the `rank` nonzero
+    /// rows of [`Matrix::rref`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 2, 3>::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+    /// assert_eq!(a.row_space(), vec![Vector::from([1.0, 2.0, 3.0])]);
+    /// ```
+    pub fn row_space(&self) -> Vec<Vector<T, N>> {
+        let rref = self.rref();
+        let reduced = rref.reduced();
+        (0..rref.rank())
+            .map(|k| Vector::from(reduced.store[k]))
+            .collect()
+    }
+
+    /// Returns a basis of the left null space, the vectors `y` with `Aᵀ * y = 0`.
+    ///
+    /// This is the null space of the transpose, with `M - rank` vectors, and it is the orthogonal
+    /// complement of the column space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::{Matrix, Vector};
+    ///
+    /// let a = Matrix::<f64, 3, 2>::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+    /// assert_eq!(a.left_null_space(), vec![Vector::from([-1.0, -1.0, 1.0])]);
+    /// ```
+    pub fn left_null_space(&self) -> Vec<Vector<T, M>> {
+        let mut a = *self;
+        a.transpose().null_space()
+    }
+}
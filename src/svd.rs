@@ -0,0 +1,343 @@
+//! # mini_matrix
+//!
+//! Singular value decomposition by one-sided Jacobi rotations.
+
+use num::traits::float::TotalOrder;
+use num::Float;
+use std::iter::Sum;
+
+use crate::{LinalgError, Matrix, Vector};
+
+/// Upper bound on the number of Jacobi sweeps; convergence is quadratic and typically takes fewer
+/// than ten sweeps.
+const MAX_SWEEPS: usize = 100;
+
+/// The singular value decomposition `A = U * Σ * Vᵀ` of an `M x N` matrix.
+///
+/// `U` (`M x M`) and `V` (`N x N`) are orthogonal and `Σ` (`M x N`) is zero except for the
+/// `min(M, N)` non-negative singular values on its diagonal, which are sorted in decreasing
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use mini_matrix::Matrix;
+///
+/// let a = Matrix::<f64, 2, 3>::from([[3.0, 0.0, 0.0], [0.0, 0.0, -4.0]]);
+/// let svd = a.svd().unwrap();
+/// assert!((svd.singular_values()[0] - 4.0).abs() < 1e-12);
+/// assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<T, const M: usize, const N: usize> {
+    u: Matrix<T, M, M>,
+    singular_values: Vec<T>,
+    vt: Matrix<T, N, N>,
+}
+
+/// The left singular vectors, singular values and right singular vectors computed by
+/// [`one_sided_jacobi`].
+type JacobiFactors<T, const R: usize, const C: usize> = (Matrix<T, R, R>, Vec<T>, Matrix<T, C, C>);
+
+/// Runs one-sided Jacobi on the columns of an `R x C` matrix with `R >= C`.
+///
+/// Returns the full left singular vectors (`R x R`), the `C` singular values in decreasing order
+/// and the right singular vectors (`C x C`, not transposed), or [`LinalgError::NoConvergence`] if
+/// the last sweep still had to rotate.
+fn one_sided_jacobi<T, const R: usize, const C: usize>(
+    a: &Matrix<T, R, C>,
+) -> Result<JacobiFactors<T, R, C>, LinalgError>
+where
+    T: Float + Default + Sum + TotalOrder,
+{
+    let mut w = [Vector::<T, R>::zero(); C];
+    let mut v = [Vector::<T, C>::zero(); C];
+    for j in 0..C {
+        for i in 0..R {
+            w[j][i] = a[(i, j)];
+        }
+        v[j][j] = T::one();
+    }
+
+    let two = T::one() + T::one();
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..C {
+            for q in p + 1..C {
+                let alpha = w[p].dot(&w[p]);
+                let beta = w[q].dot(&w[q]);
+                let gamma = w[p].dot(&w[q]);
+                // A column whose squared norm underflows has converged to zero; rotating it
+                // against another one would only keep shuffling rounding noise.
+                if gamma == T::zero()
+                    || alpha == T::zero()
+                    || beta == T::zero()
+                    || gamma.abs() <= T::epsilon() * alpha.sqrt() * beta.sqrt()
+                {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                let s = c * t;
+
+                let (wp, wq) = (w[p], w[q]);
+                w[p] = wp * c - wq * s;
+                w[q] = wp * s + wq * c;
+                let (vp, vq) = (v[p], v[q]);
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NoConvergence);
+    }
+
+    let mut order: Vec<usize> = (0..C).collect();
+    let norms: Vec<T> = w.iter().map(|column| column.norm()).collect();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let largest = order.first().map_or(T::zero(), |&j| norms[j]);
+    let negligible = T::from(R).unwrap() * T::epsilon() * largest;
+
+    let mut u_columns: Vec<Vector<T, R>> = Vec::with_capacity(R);
+    let mut singular_values = Vec::with_capacity(C);
+    let mut right = Matrix::zero();
+    for (k, &j) in order.iter().enumerate() {
+        singular_values.push(norms[j]);
+        for i in 0..C {
+            right[(i, k)] = v[j][i];
+        }
+        if norms[j] > negligible {
+            u_columns.push(w[j] * (T::one() / norms[j]));
+        }
+    }
+
+    // Complete the left singular vectors to an orthonormal basis of the whole space. Each new
+    // column is the standard basis vector with the largest component outside the current span,
+    // which is at least `1 / √R` long, so the Gram-Schmidt step stays well conditioned.
+    while u_columns.len() < R {
+        let residual = |e: usize| {
+            let mut candidate = Vector::<T, R>::zero();
+            candidate[e] = T::one();
+            for _ in 0..2 {
+                for column in u_columns.iter() {
+                    candidate = candidate - *column * column.dot(&candidate);
+                }
+            }
+            candidate
+        };
+        let best = (0..R)
+            .map(residual)
+            .max_by(|x, y| x.norm().total_cmp(&y.norm()))
+            .unwrap();
+        u_columns.push(best * (T::one() / best.norm()));
+    }
+
+    let mut left = Matrix::zero();
+    for (k, column) in u_columns.iter().enumerate() {
+        for i in 0..R {
+            left[(i, k)] = column[i];
+        }
+    }
+    Ok((left, singular_values, right))
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float + Default + Sum + TotalOrder,
+{
+    /// Computes the singular value decomposition of the matrix.
+    ///
+    /// The decomposition uses one-sided Jacobi rotations, which orthogonalise the columns of the
+    /// matrix (or of its transpose when `M < N`) until every pair is orthogonal to working
+    /// precision. This is slower than bidiagonalisation-based methods for large matrices but
+    /// computes small singular values to high relative accuracy.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Svd)` on success, or [`LinalgError::NoConvergence`] if the last of the 100
+    /// sweeps still had to rotate, which happens for matrices with NaN entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 3, 2>::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+    /// let svd = a.svd().unwrap();
+    /// let (u, sigma, vt) = (svd.u(), svd.sigma(), svd.vt());
+    /// for i in 0..3 {
+    ///     for j in 0..2 {
+    ///         let mut product = 0.0;
+    ///         for k in 0..3 {
+    ///             for l in 0..2 {
+    ///                 product += u[(i, k)] * sigma[(k, l)] * vt[(l, j)];
+    ///             }
+    ///         }
+    ///         assert!((product - a[(i, j)]).abs() < 1e-12);
+    ///     }
+    /// }
+    /// ```
+    pub fn svd(&self) -> Result<Svd<T, M, N>, LinalgError> {
+        if M >= N {
+            let (u, singular_values, v) = one_sided_jacobi(self)?;
+            let mut vt = Matrix::zero();
+            for i in 0..N {
+                for j in 0..N {
+                    vt[(i, j)] = v[(j, i)];
+                }
+            }
+            Ok(Svd {
+                u,
+                singular_values,
+                vt,
+            })
+        } else {
+            let mut transposed = *self;
+            let (v, singular_values, u) = one_sided_jacobi(&transposed.transpose())?;
+            let mut vt = Matrix::zero();
+            for i in 0..N {
+                for j in 0..N {
+                    vt[(i, j)] = v[(j, i)];
+                }
+            }
+            Ok(Svd {
+                u,
+                singular_values,
+                vt,
+            })
+        }
+    }
+
+    /// Returns the `min(M, N)` singular values of the matrix in decreasing order, or
+    /// [`LinalgError::NoConvergence`] if [`Matrix::svd`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[2.0, 0.0], [0.0, -5.0]]);
+    /// assert_eq!(a.singular_values(), Ok(vec![5.0, 2.0]));
+    /// ```
+    pub fn singular_values(&self) -> Result<Vec<T>, LinalgError> {
+        Ok(self.svd()?.singular_values)
+    }
+
+    /// Computes the numerical rank: the number of singular values greater than `tolerance`.
+    ///
+    /// Unlike [`Matrix::rank`], this is robust to rounding noise: a matrix that is singular up to
+    /// a perturbation of size `tolerance` is reported as rank deficient. Returns
+    /// [`LinalgError::NoConvergence`] if [`Matrix::svd`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[1.0, 1.0], [1.0, 1.0 + 1e-14]]);
+    /// assert_eq!(a.rank_with_tolerance(1e-10), Ok(1));
+    /// assert_eq!(a.rank_with_tolerance(1e-16), Ok(2));
+    /// ```
+    pub fn rank_with_tolerance(&self, tolerance: T) -> Result<usize, LinalgError> {
+        Ok(self.svd()?.rank(tolerance))
+    }
+
+    /// Computes the spectral norm `‖A‖₂`, the largest singular value, or returns
+    /// [`LinalgError::NoConvergence`] if [`Matrix::svd`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[3.0, 0.0], [4.0, 0.0]]);
+    /// assert!((a.spectral_norm().unwrap() - 5.0).abs() < 1e-12);
+    /// ```
+    pub fn spectral_norm(&self) -> Result<T, LinalgError> {
+        Ok(self.svd()?.spectral_norm())
+    }
+
+    /// Computes the 2-norm condition number `σ_max / σ_min`.
+    ///
+    /// The result is infinite for rank-deficient matrices. Returns
+    /// [`LinalgError::NoConvergence`] if [`Matrix::svd`] fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mini_matrix::Matrix;
+    ///
+    /// let a = Matrix::<f64, 2, 2>::from([[10.0, 0.0], [0.0, 0.1]]);
+    /// assert!((a.condition_number().unwrap() - 100.0).abs() < 1e-10);
+    /// ```
+    pub fn condition_number(&self) -> Result<T, LinalgError> {
+        Ok(self.svd()?.condition_number())
+    }
+}
+
+impl<T, const M: usize, const N: usize> Svd<T, M, N>
+where
+    T: Float + Default + Sum,
+{
+    /// Returns the `M x M` orthogonal matrix `U` of left singular vectors.
+    pub fn u(&self) -> Matrix<T, M, M> {
+        self.u
+    }
+
+    /// Returns the `M x N` diagonal matrix `Σ` of singular values.
+    pub fn sigma(&self) -> Matrix<T, M, N> {
+        let mut sigma = Matrix::zero();
+        for (i, &value) in self.singular_values.iter().enumerate() {
+            sigma[(i, i)] = value;
+        }
+        sigma
+    }
+
+    /// Returns the `N x N` orthogonal matrix `Vᵀ` whose rows are the right singular vectors.
+    pub fn vt(&self) -> Matrix<T, N, N> {
+        self.vt
+    }
+
+    /// Returns the singular values in decreasing order.
+    pub fn singular_values(&self) -> &[T] {
+        &self.singular_values
+    }
+
+    /// Returns the threshold `max(M, N) * ε * σ_max` below which singular values are treated as
+    /// zero by default.
+    pub(crate) fn default_tolerance(&self) -> T {
+        T::from(M.max(N)).unwrap() * T::epsilon() * self.spectral_norm()
+    }
+
+    /// Returns the number of singular values greater than `tolerance`.
+    pub fn rank(&self, tolerance: T) -> usize {
+        self.singular_values
+            .iter()
+            .filter(|&&value| value > tolerance)
+            .count()
+    }
+
+    /// Returns the spectral norm `‖A‖₂`, the largest singular value.
+    pub fn spectral_norm(&self) -> T {
+        self.singular_values.first().copied().unwrap_or(T::zero())
+    }
+
+    /// Returns the 2-norm condition number `σ_max / σ_min`, infinite for rank-deficient matrices.
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.first(), self.singular_values.last()) {
+            (Some(_), Some(&smallest)) if smallest == T::zero() => T::infinity(),
+            (Some(&largest), Some(&smallest)) => largest / smallest,
+            _ => T::one(),
+        }
+    }
+}
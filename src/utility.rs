@@ -1,5 +1,5 @@
 use crate::Matrix;
-use num::Num;
+use num::{Float, Num};
 use std::ops::{AddAssign, Mul, Neg};
 
 impl<T, const M: usize, const N: usize> Matrix<T, M, N>
@@ -70,3 +70,13 @@ where
         cofactor_matrix
     }
 }
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default,
+{
+    /// Returns `true` if `|aᵢⱼ - aⱼᵢ| <= tolerance` for every pair of off-diagonal entries.
+    pub(crate) fn is_symmetric_within(&self, tolerance: T) -> bool {
+        (0..N).all(|i| (0..i).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tolerance))
+    }
+}
@@ -35,7 +35,7 @@ impl<T, const N: usize> Index<usize> for Vector<T, N> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.store.index(index)
+        self.store.index(index)
     }
 }
 
@@ -222,7 +222,7 @@ where
     /// assert_eq!(v3, Vector::from([5, 7, 9]));
     /// ```
     fn add(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for (e1, e2) in result.store.iter_mut().zip(rhs.store.iter()) {
             *e1 = *e1 + *e2;
         }
@@ -269,7 +269,7 @@ where
     /// assert_eq!(v3, Vector::from([3, 3, 3]));
     /// ```
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for (e1, e2) in result.store.iter_mut().zip(rhs.store.iter()) {
             *e1 = *e1 - *e2;
         }
@@ -315,9 +315,9 @@ where
     /// assert_eq!(v2, Vector::from([2.0, 4.0, 6.0]));
     /// ```
     fn mul(self, scalar: T) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for element in result.store.iter_mut() {
-            *element = element.clone() * scalar;
+            *element = *element * scalar;
         }
         result
     }
@@ -345,7 +345,7 @@ where
     fn mul(self, rhs: Self) -> Self::Output {
         self.store
             .into_iter()
-            .zip(rhs.store.into_iter())
+            .zip(rhs.store)
             .map(|(a, b)| a * b)
             .sum()
     }
@@ -369,7 +369,7 @@ where
     /// assert_eq!(v2, Vector::from([-1, 2, -3]));
     /// ```
     fn neg(self) -> Self::Output {
-        let mut result = self.clone();
+        let mut result = self;
         for element in result.store.iter_mut() {
             *element = -(*element);
         }
@@ -399,7 +399,7 @@ where
             }
             write!(f, "{:.1}", val)?;
         }
-        write!(f, "]\n")
+        writeln!(f, "]")
     }
 }
 
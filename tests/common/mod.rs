@@ -0,0 +1,22 @@
+use mini_matrix::Matrix;
+
+/// Asserts that `a` matches the expected matrix `b` entry by entry, to within `tolerance`
+/// relative to the size of the expected entry once it exceeds one.
+pub fn assert_close<const M: usize, const N: usize>(
+    a: &Matrix<f64, M, N>,
+    b: &Matrix<f64, M, N>,
+    tolerance: f64,
+) {
+    for i in 0..M {
+        for j in 0..N {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).abs() <= tolerance * (1.0 + b[(i, j)].abs()),
+                "{} != {} at ({}, {})",
+                a[(i, j)],
+                b[(i, j)],
+                i,
+                j
+            );
+        }
+    }
+}
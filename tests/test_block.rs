@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod block_tests {
+    use mini_matrix::{Matrix, Vector};
+    use num::{BigInt, BigRational};
+
+    #[test]
+    fn test_stack_and_split_round_trip() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[7, 8], [9, 10]]);
+        let ab: Matrix<i32, 2, 5> = a.hstack(&b);
+        assert_eq!(ab.store, [[1, 2, 3, 7, 8], [4, 5, 6, 9, 10]]);
+        assert_eq!(ab.split_columns(), (a, b));
+
+        let c = Matrix::from([[0, -1, -2]]);
+        let ac: Matrix<i32, 3, 3> = a.vstack(&c);
+        assert_eq!(ac.store, [[1, 2, 3], [4, 5, 6], [0, -1, -2]]);
+        assert_eq!(ac.split_rows(), (a, c));
+
+        // Splitting at the edges gives an empty block.
+        let (empty, whole): (Matrix<i32, 0, 3>, Matrix<i32, 2, 3>) = a.split_rows();
+        assert_eq!(empty.store.len(), 0);
+        assert_eq!(whole, a);
+    }
+
+    #[test]
+    fn test_append_vectors() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let with_column: Matrix<f64, 2, 3> = a.append_column(&Vector::from([5.0, 6.0]));
+        assert_eq!(with_column.store, [[1.0, 2.0, 5.0], [3.0, 4.0, 6.0]]);
+        let with_row: Matrix<f64, 3, 2> = a.append_row(&Vector::from([5.0, 6.0]));
+        assert_eq!(with_row.store, [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        // Appending a column is hstack with a single-column matrix.
+        let column = Matrix::from([[5.0], [6.0]]);
+        let stacked: Matrix<f64, 2, 3> = a.hstack(&column);
+        assert_eq!(with_column, stacked);
+    }
+
+    #[test]
+    fn test_augmented_system() {
+        let a = Matrix::<f64, 3, 3>::from([[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+        let b = Vector::from([8.0, -11.0, -3.0]);
+        let augmented: Matrix<f64, 3, 4> = a.append_column(&b);
+        let rref = augmented.rref();
+        assert_eq!(rref.pivot_columns(), &[0, 1, 2]);
+
+        let (identity, solution): (Matrix<f64, 3, 3>, Matrix<f64, 3, 1>) =
+            rref.reduced().split_columns();
+        assert_eq!(identity, Matrix::identity());
+        for (i, expected) in [2.0, 3.0, -1.0].into_iter().enumerate() {
+            assert!((solution[(i, 0)] - expected).abs() < 1e-12);
+        }
+
+        // An inconsistent system gets a pivot in the augmented column.
+        let singular = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 4.0]]);
+        let inconsistent: Matrix<f64, 2, 3> = singular.append_column(&Vector::from([1.0, 3.0]));
+        assert_eq!(inconsistent.rref().pivot_columns(), &[0, 2]);
+    }
+
+    #[test]
+    fn test_block_diagonal() {
+        let a = Matrix::<f64, 2, 2>::from([[2.0, 1.0], [1.0, 3.0]]);
+        let b = Matrix::<f64, 2, 2>::from([[4.0, -1.0], [0.5, 1.0]]);
+        let ab: Matrix<f64, 4, 4> = a.block_diagonal(&b);
+        assert_eq!(
+            ab.store,
+            [
+                [2.0, 1.0, 0.0, 0.0],
+                [1.0, 3.0, 0.0, 0.0],
+                [0.0, 0.0, 4.0, -1.0],
+                [0.0, 0.0, 0.5, 1.0],
+            ]
+        );
+        assert!((ab.determinant() - a.determinant() * b.determinant()).abs() < 1e-12);
+
+        // Rectangular blocks.
+        let row = Matrix::from([[1, 2, 3]]);
+        let column = Matrix::from([[4], [5]]);
+        let rc: Matrix<i32, 3, 4> = row.block_diagonal(&column);
+        assert_eq!(rc.store, [[1, 2, 3, 0], [0, 0, 0, 4], [0, 0, 0, 5]]);
+    }
+
+    #[test]
+    fn test_non_copy_elements() {
+        let r = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let a = Matrix::from([[r(1, 2)], [r(-1, 3)]]);
+        let b = Matrix::from([[r(5, 7)]]);
+        let ab: Matrix<BigRational, 3, 2> = a.block_diagonal(&b);
+        assert_eq!(ab[(2, 1)], r(5, 7));
+        assert_eq!(ab[(0, 1)], r(0, 1));
+
+        let stacked: Matrix<BigRational, 3, 1> = a.vstack(&b);
+        let (top, bottom): (Matrix<BigRational, 2, 1>, Matrix<BigRational, 1, 1>) =
+            stacked.split_rows();
+        assert_eq!(top, a);
+        assert_eq!(bottom, b);
+    }
+}
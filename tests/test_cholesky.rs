@@ -0,0 +1,126 @@
+mod common;
+
+#[cfg(test)]
+mod cholesky_tests {
+    use crate::common::assert_close;
+    use mini_matrix::{LinalgError, Matrix, Vector};
+
+    fn assert_solves<const N: usize>(
+        a: &Matrix<f64, N, N>,
+        x: &Vector<f64, N>,
+        b: &Vector<f64, N>,
+    ) {
+        let ax = *a * *x;
+        for i in 0..N {
+            assert!((ax[i] - b[i]).abs() < 1e-10, "{} != {}", ax[i], b[i]);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_factor() {
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let l = a.cholesky().unwrap().l();
+        assert_eq!(l.store, [[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]]);
+
+        let mut l_t = l;
+        assert_close(&(l * l_t.transpose()), &a, 1e-10);
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let a: Matrix<f64, 4, 4> = Matrix::from([
+            [10., 1., 2., 0.],
+            [1., 8., 0., 3.],
+            [2., 0., 9., 1.],
+            [0., 3., 1., 7.],
+        ]);
+        let b = Vector::from([1., 2., 3., 4.]);
+        let x = a.cholesky().unwrap().solve(&b);
+        assert_solves(&a, &x, &b);
+    }
+
+    #[test]
+    fn test_cholesky_errors() {
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 2.], [2., 1.]]);
+        assert_eq!(a.cholesky(), Err(LinalgError::NotPositiveDefinite));
+
+        // Positive semi-definite is not enough.
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 1.], [1., 1.]]);
+        assert_eq!(a.cholesky(), Err(LinalgError::NotPositiveDefinite));
+
+        let a: Matrix<f64, 2, 2> = Matrix::from([[2., 1.], [0., 2.]]);
+        assert_eq!(a.cholesky(), Err(LinalgError::NotSymmetric));
+        assert_eq!(a.ldlt(), Err(LinalgError::NotSymmetric));
+    }
+
+    #[test]
+    fn test_ldlt_positive_definite() {
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let ldlt = a.ldlt().unwrap();
+        let mut p = ldlt.p();
+        let mut l_t = ldlt.l();
+        let pap = p * a * p.transpose();
+        assert_close(&(ldlt.l() * ldlt.d() * l_t.transpose()), &pap, 1e-10);
+
+        let b = Vector::from([1., 2., 3.]);
+        assert_solves(&a, &ldlt.solve(&b).unwrap(), &b);
+    }
+
+    #[test]
+    fn test_ldlt_indefinite() {
+        let a: Matrix<f64, 4, 4> = Matrix::from([
+            [0., 1., 2., 3.],
+            [1., 0., 4., 5.],
+            [2., 4., 0., 6.],
+            [3., 5., 6., 0.],
+        ]);
+        let ldlt = a.ldlt().unwrap();
+        let mut p = ldlt.p();
+        let mut l_t = ldlt.l();
+        let pap = p * a * p.transpose();
+        assert_close(&(ldlt.l() * ldlt.d() * l_t.transpose()), &pap, 1e-10);
+
+        let l = ldlt.l();
+        for i in 0..4 {
+            assert_eq!(l[(i, i)], 1.);
+            for j in i + 1..4 {
+                assert_eq!(l[(i, j)], 0.);
+            }
+        }
+
+        let b = Vector::from([6., 10., 12., 14.]);
+        assert_solves(&a, &ldlt.solve(&b).unwrap(), &b);
+    }
+
+    #[test]
+    fn test_ldlt_semi_definite() {
+        // Rank one: [1 2 3]ᵀ [1 2 3].
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 3.], [2., 4., 6.], [3., 6., 9.]]);
+        let ldlt = a.ldlt().unwrap();
+        let mut p = ldlt.p();
+        let mut l_t = ldlt.l();
+        let pap = p * a * p.transpose();
+        assert_close(&(ldlt.l() * ldlt.d() * l_t.transpose()), &pap, 1e-10);
+
+        assert_eq!(
+            ldlt.solve(&Vector::from([1., 2., 3.])),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_ldlt_nearly_singular_block() {
+        // The trailing 2x2 pivot has eigenvalues 1.6e-15 and -4e-16, the latter below the
+        // tolerance 3ε of this matrix.
+        let a: Matrix<f64, 3, 3> =
+            Matrix::from([[1., 0., 0.], [0., 6e-16, 1e-15], [0., 1e-15, 6e-16]]);
+        let ldlt = a.ldlt().unwrap();
+        assert_ne!(ldlt.d()[(2, 1)], 0.);
+        assert_eq!(
+            ldlt.solve(&Vector::from([1., 1., 1.])),
+            Err(LinalgError::Singular)
+        );
+    }
+}
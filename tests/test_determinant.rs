@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod determinant_tests {
+    use mini_matrix::{LinalgError, Matrix};
+    use num::BigInt;
+
+    /// The Vandermonde matrix of `1, 2, ..., N`, whose determinant is `Π_{i<j} (j - i)`.
+    fn vandermonde<const N: usize>() -> Matrix<i64, N, N> {
+        let mut v = Matrix::zero();
+        for i in 0..N {
+            for j in 0..N {
+                v[(i, j)] = (i as i64 + 1).pow(j as u32);
+            }
+        }
+        v
+    }
+
+    fn vandermonde_determinant(n: usize) -> BigInt {
+        let mut det = BigInt::from(1);
+        for i in 1..=n {
+            for j in i + 1..=n {
+                det *= BigInt::from(j - i);
+            }
+        }
+        det
+    }
+
+    #[test]
+    fn test_checked_matches_determinant() {
+        let a = Matrix::<i32, 3, 3>::from([[-2, -8, 4], [1, -23, 4], [0, 6, 4]]);
+        assert_eq!(a.checked_determinant(), Ok(288));
+        assert_eq!(a.determinant(), 288);
+
+        let b = Matrix::<i64, 5, 5>::from([
+            [3, 1, 4, 1, 5],
+            [9, 2, 6, 5, 3],
+            [5, 8, 9, 7, 9],
+            [3, 2, 3, 8, 4],
+            [6, 2, 6, 4, 3],
+        ]);
+        assert_eq!(b.checked_determinant(), Ok(b.determinant()));
+        assert_eq!(b.determinant_bigint(), BigInt::from(b.determinant()));
+
+        let v = vandermonde::<6>();
+        assert_eq!(
+            BigInt::from(v.checked_determinant().unwrap()),
+            vandermonde_determinant(6)
+        );
+    }
+
+    #[test]
+    fn test_checked_handles_zero_pivots() {
+        // The leading zero forces a row swap, which flips the sign.
+        let a = Matrix::<i32, 3, 3>::from([[0, 1, 2], [1, 0, 3], [4, -3, 8]]);
+        assert_eq!(a.checked_determinant(), Ok(-2));
+        assert_eq!(a.determinant(), -2);
+
+        let singular = Matrix::<i32, 3, 3>::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(singular.checked_determinant(), Ok(0));
+        assert_eq!(Matrix::<i32, 0, 0>::zero().checked_determinant(), Ok(1));
+    }
+
+    #[test]
+    fn test_checked_pivots_on_min_value() {
+        // Partial pivoting picks i32::MIN, whose magnitude does not fit in i32 itself.
+        let a = Matrix::<i32, 2, 2>::from([[1, 0], [i32::MIN, 1]]);
+        assert_eq!(a.checked_determinant(), Ok(1));
+        assert_eq!(a.determinant(), 1);
+    }
+
+    #[test]
+    fn test_overflow_is_detected() {
+        let a = Matrix::<i32, 2, 2>::from([[i32::MAX, 2], [2, i32::MAX]]);
+        assert_eq!(a.checked_determinant(), Err(LinalgError::Overflow));
+
+        let v = vandermonde::<12>();
+        assert_eq!(v.checked_determinant(), Err(LinalgError::Overflow));
+
+        // Only the final negation overflows here: the determinant is 2³¹, which does not fit.
+        let b = Matrix::<i32, 2, 2>::from([[0, i32::MIN], [1, 0]]);
+        assert_eq!(b.checked_determinant(), Err(LinalgError::Overflow));
+    }
+
+    #[test]
+    fn test_bigint_path() {
+        let v = vandermonde::<12>();
+        assert_eq!(v.determinant_bigint(), vandermonde_determinant(12));
+
+        let a = Matrix::<i32, 2, 2>::from([[i32::MAX, 2], [2, i32::MAX]]);
+        assert_eq!(
+            a.determinant_bigint(),
+            BigInt::from(i32::MAX) * BigInt::from(i32::MAX) - 4
+        );
+
+        let b = Matrix::<i32, 2, 2>::from([[0, i32::MIN], [1, 0]]);
+        assert_eq!(b.determinant_bigint(), -BigInt::from(i32::MIN));
+        assert_eq!(
+            Matrix::<u8, 2, 2>::from([[200, 100], [100, 200]]).determinant_bigint(),
+            BigInt::from(30000)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod dynamic_tests {
+    use mini_matrix::{DMatrix, DVector, LinalgError, Matrix, Vector};
+    use num::{BigInt, BigRational};
+
+    #[test]
+    fn test_construction() {
+        let a = DMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(a.size(), (2, 3));
+        assert_eq!(a.row(1), &[4, 5, 6]);
+        assert_eq!(
+            a,
+            DMatrix::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+        );
+
+        assert_eq!(
+            DMatrix::new(2, 3, vec![1, 2, 3]),
+            Err(LinalgError::DimensionMismatch {
+                expected: (6, 1),
+                found: (3, 1)
+            })
+        );
+        assert_eq!(
+            DMatrix::from_vecs(vec![vec![1, 2], vec![3, 4, 5]]),
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+
+        let empty = DMatrix::<f64>::from_vecs(vec![]).unwrap();
+        assert_eq!(empty.size(), (0, 0));
+        assert_eq!(empty.determinant(), Ok(1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let a = DMatrix::<i32>::zero(2, 3);
+        let _ = a[(0, 3)];
+    }
+
+    #[test]
+    fn test_add_sub_scl() {
+        let mut a = DMatrix::from_vecs(vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let b = DMatrix::from_vecs(vec![vec![0.5, 0.5], vec![1., -1.]]).unwrap();
+        a.add(&b).unwrap();
+        assert_eq!(a.as_slice(), &[1.5, 2.5, 4., 3.]);
+        a.sub(&b).unwrap();
+        assert_eq!(a.as_slice(), &[1., 2., 3., 4.]);
+        a.scl(2.);
+        assert_eq!(a.as_slice(), &[2., 4., 6., 8.]);
+
+        let wrong = DMatrix::zero(2, 3);
+        assert_eq!(
+            a.add(&wrong),
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+        assert_eq!(a.as_slice(), &[2., 4., 6., 8.]);
+
+        assert_eq!((a.clone() + b.clone() - b).as_slice(), a.as_slice());
+        assert_eq!((-(a.clone() * 0.5)).as_slice(), &[-1., -2., -3., -4.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch: expected 2x2, found 3x2")]
+    fn test_add_operator_mismatch() {
+        let _ = DMatrix::<i32>::zero(2, 2) + DMatrix::zero(3, 2);
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let a = DMatrix::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let b = DMatrix::from_vecs(vec![vec![7, 8], vec![9, 10], vec![11, 12]]).unwrap();
+        assert_eq!(a.mul_mat(&b).unwrap().as_slice(), &[58, 64, 139, 154]);
+        assert_eq!((b.clone() * a.clone()).size(), (3, 3));
+        assert_eq!(
+            a.mul_mat(&a),
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            })
+        );
+
+        let v = DVector::from(vec![1, 0, -1]);
+        assert_eq!(a.mul_vec(&v).unwrap().store, vec![-2, -2]);
+        assert_eq!((a.clone() * v).store, vec![-2, -2]);
+        assert_eq!(
+            a.mul_vec(&DVector::from(vec![1, 2])),
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_transpose_and_trace() {
+        let a = DMatrix::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let t = a.transpose();
+        assert_eq!(t.size(), (3, 2));
+        assert_eq!(t.transpose(), a);
+        assert_eq!(a.trace(), Err(LinalgError::NotSquare { rows: 2, cols: 3 }));
+        assert_eq!(a.mul_mat(&t).unwrap().trace(), Ok(91));
+    }
+
+    #[test]
+    fn test_row_echelon_and_rank() {
+        let a = DMatrix::from_vecs(vec![
+            vec![1., 2., 3., 4.],
+            vec![5., 6., 7., 8.],
+            vec![9., 10., 11., 12.],
+        ])
+        .unwrap();
+        let r = a.row_echelon();
+        assert_eq!(r.row(2), &[0., 0., 0., 0.]);
+        assert_eq!(r[(0, 0)], 1.);
+        assert_eq!(r[(1, 1)], 1.);
+        assert_eq!(a.rank(), 2);
+
+        // The pivot search has to swap entire rows, including the columns past the row count.
+        let b = DMatrix::from_vecs(vec![vec![0., 1., 5.], vec![1., 0., 7.]]).unwrap();
+        assert_eq!(b.row_echelon().as_slice(), &[1., 0., 7., 0., 1., 5.]);
+        assert_eq!(b.rank(), 2);
+
+        let zero = DMatrix::<f64>::zero(3, 2);
+        assert_eq!(zero.rank(), 0);
+
+        // Elements only need to be cloneable, so exact rationals work too.
+        let r = |n: i64| BigRational::from_integer(BigInt::from(n));
+        let exact = DMatrix::from(Matrix::from([[r(2), r(4), r(6)], [r(1), r(3), r(5)]]));
+        let rref = exact.row_echelon();
+        assert_eq!(rref[(0, 2)], r(-1));
+        assert_eq!(rref[(1, 2)], r(2));
+        assert_eq!(exact.rank(), 2);
+    }
+
+    #[test]
+    fn test_determinant_and_inverse() {
+        let a = DMatrix::from_vecs(vec![vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 2]]).unwrap();
+        assert_eq!(a.determinant(), Ok(6));
+
+        let fixed: Matrix<f64, 3, 3> = Matrix::from([[2., 0., 1.], [1., 3., 2.], [1., 1., 2.]]);
+        let dynamic = DMatrix::from(fixed);
+        let inverse = dynamic.inverse().unwrap();
+        let expected = fixed.inverse().unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((inverse[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+        let identity = dynamic.mul_mat(&inverse).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((identity[(i, j)] - expected).abs() < 1e-12);
+            }
+        }
+
+        let singular = DMatrix::from_vecs(vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        assert_eq!(singular.inverse(), Err(LinalgError::Singular));
+        assert_eq!(
+            DMatrix::<f64>::zero(2, 3).inverse(),
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let fixed = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let dynamic = DMatrix::from(fixed);
+        assert_eq!(dynamic.size(), (2, 3));
+        assert_eq!(Matrix::<i32, 2, 3>::try_from(dynamic.clone()), Ok(fixed));
+        assert_eq!(
+            Matrix::<i32, 3, 2>::try_from(dynamic),
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            })
+        );
+
+        let v = Vector::from([1., 2., 3.]);
+        let dv = DVector::from(v);
+        assert_eq!(dv.store, vec![1., 2., 3.]);
+        assert_eq!(Vector::<f64, 3>::try_from(dv.clone()), Ok(v));
+        assert_eq!(
+            Vector::<f64, 2>::try_from(dv),
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_dvector_operations() {
+        let mut u = DVector::from(vec![1., 2., 3.]);
+        let v = DVector::from(vec![4., 5., 6.]);
+        assert_eq!(u.dot(&v), Ok(32.));
+        u.add(&v).unwrap();
+        assert_eq!(u.store, vec![5., 7., 9.]);
+        u.sub(&v).unwrap();
+        u.scl(2.);
+        assert_eq!(u.store, vec![2., 4., 6.]);
+        assert_eq!((u.clone() - v.clone() + v.clone()).store, u.store);
+        assert_eq!((-(v.clone() * 2.)).store, vec![-8., -10., -12.]);
+
+        let w = DVector::from(vec![3., -4.]);
+        assert_eq!(w.norm(), 5.);
+        assert_eq!(w.norm_1(), 7.);
+        assert_eq!(w.norm_inf(), 4.);
+        assert_eq!(
+            w.dot(&v),
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+        assert_eq!(
+            LinalgError::NotSquare { rows: 2, cols: 3 }.to_string(),
+            "matrix is not square (2x3)"
+        );
+    }
+}
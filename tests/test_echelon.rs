@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod echelon_tests {
+    use mini_matrix::Matrix;
+
+    #[test]
+    fn test_rounding_residue_is_ignored() {
+        let a = Matrix::<f64, 3, 3>::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+        assert_eq!(a.echelon_rank(), 2);
+        let rref = a.rref();
+        assert_eq!(rref.pivot_columns(), &[0, 1]);
+        assert_eq!(rref.free_columns(), vec![2]);
+        assert_eq!(rref.reduced().store[2], [0.0, 0.0, 0.0]);
+        assert!((rref.reduced()[(0, 2)] + 1.0).abs() < 1e-12);
+        assert!((rref.reduced()[(1, 2)] - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_largest_pivot_is_chosen() {
+        // Taking 1e-20 as the pivot would lose the second row to rounding.
+        let a = Matrix::<f64, 2, 2>::from([[1e-20, 1.0], [1.0, 1.0]]);
+        let rref = a.rref();
+        assert_eq!(rref.rank(), 2);
+        assert_eq!(rref.reduced(), Matrix::identity());
+    }
+
+    #[test]
+    fn test_explicit_tolerance() {
+        let a = Matrix::<f64, 3, 3>::from([[1.0, 0.0, 0.0], [0.0, 1e-8, 0.0], [0.0, 0.0, 1e-3]]);
+        assert_eq!(a.echelon_rank(), 3);
+        assert_eq!(a.echelon_rank_with_tolerance(1e-6), 2);
+        assert_eq!(a.echelon_rank_with_tolerance(1e-2), 1);
+        assert_eq!(a.echelon_rank_with_tolerance(-1.0), 3);
+
+        let rref = a.rref_with_tolerance(1e-6);
+        assert_eq!(rref.pivot_columns(), &[0, 2]);
+        assert_eq!(
+            rref.reduced().store,
+            [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_rectangular_shapes() {
+        let wide = Matrix::<f64, 2, 4>::from([[0.0, 2.0, 4.0, 2.0], [0.0, 1.0, 2.0, 3.0]]);
+        let rref = wide.rref();
+        assert_eq!(rref.pivot_columns(), &[1, 3]);
+        assert_eq!(rref.free_columns(), vec![0, 2]);
+        assert_eq!(
+            rref.reduced().store,
+            [[0.0, 1.0, 2.0, 0.0], [0.0, 0.0, 0.0, 1.0]]
+        );
+
+        let tall = Matrix::<f64, 4, 2>::from([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0], [4.0, 8.0]]);
+        let rref = tall.rref();
+        assert_eq!(rref.pivot_columns(), &[0]);
+        assert_eq!(rref.reduced()[(0, 1)], 2.0);
+        assert_eq!(tall.echelon_rank(), tall.rank());
+    }
+
+    #[test]
+    fn test_zero_and_empty() {
+        let zero = Matrix::<f64, 3, 2>::zero().rref();
+        assert_eq!(zero.rank(), 0);
+        assert!(zero.pivot_columns().is_empty());
+        assert_eq!(zero.free_columns(), vec![0, 1]);
+        assert_eq!(zero.reduced(), Matrix::zero());
+
+        assert_eq!(Matrix::<f64, 0, 3>::zero().echelon_rank(), 0);
+        assert_eq!(Matrix::<f32, 3, 3>::identity().echelon_rank(), 3);
+    }
+}
@@ -0,0 +1,221 @@
+mod common;
+
+#[cfg(test)]
+mod eigen_tests {
+    use crate::common::assert_close;
+    use mini_matrix::{LinalgError, Matrix};
+    use num::Complex;
+
+    fn assert_decomposition<const N: usize>(a: &Matrix<f64, N, N>) {
+        let eigen = a.symmetric_eigen().unwrap();
+        let (values, v) = (eigen.eigenvalues(), eigen.eigenvectors());
+        let mut vt = v;
+        let vt = vt.transpose();
+        let mut lambda = Matrix::zero();
+        for i in 0..N {
+            lambda[(i, i)] = values[i];
+        }
+        assert_close(&(v * lambda * vt), a, 1e-12);
+        assert_close(&(vt * v), &Matrix::identity(), 1e-12);
+        assert!((1..N).all(|i| values[i - 1] <= values[i]));
+    }
+
+    #[test]
+    fn test_symmetric_eigen_tridiagonal() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+        assert_decomposition(&a);
+
+        let values = a.symmetric_eigen().unwrap().eigenvalues();
+        let root = 2.0_f64.sqrt();
+        assert!((values[0] - (2.0 - root)).abs() < 1e-12);
+        assert!((values[1] - 2.0).abs() < 1e-12);
+        assert!((values[2] - (2.0 + root)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_indefinite() {
+        let a: Matrix<f64, 5, 5> = Matrix::from([
+            [4., 1., -2., 2., 0.],
+            [1., 2., 0., 1., 3.],
+            [-2., 0., 3., -2., 1.],
+            [2., 1., -2., -1., 0.],
+            [0., 3., 1., 0., -4.],
+        ]);
+        assert_decomposition(&a);
+
+        let values = a.symmetric_eigen().unwrap().eigenvalues();
+        let trace: f64 = (0..5).map(|i| a[(i, i)]).sum();
+        let sum: f64 = (0..5).map(|i| values[i]).sum();
+        assert!((trace - sum).abs() < 1e-12);
+        assert!(values[0] < 0.0 && values[4] > 0.0);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_repeated() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., 1., 1.], [1., 2., 1.], [1., 1., 2.]]);
+        assert_decomposition(&a);
+
+        let values = a.symmetric_eigen().unwrap().eigenvalues();
+        assert!((values[0] - 1.0).abs() < 1e-12);
+        assert!((values[1] - 1.0).abs() < 1e-12);
+        assert!((values[2] - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_diagonal() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[3., 0., 0.], [0., -1., 0.], [0., 0., 2.]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        assert_eq!(eigen.eigenvalues().store, [-1., 2., 3.]);
+        assert_eq!(
+            eigen.eigenvectors().store,
+            [[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]]
+        );
+    }
+
+    #[test]
+    fn test_symmetric_eigen_not_symmetric() {
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 2.], [3., 1.]]);
+        assert_eq!(a.symmetric_eigen(), Err(LinalgError::NotSymmetric));
+    }
+
+    #[test]
+    fn test_symmetric_eigen_nan_does_not_panic() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., 0., 0.], [0., f64::NAN, 0.], [0., 0., 1.]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        assert_eq!(
+            eigen
+                .eigenvalues()
+                .iter()
+                .filter(|value| value.is_nan())
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_symmetric_eigen_reports_no_convergence() {
+        // The NaN diagonal entry makes every rotation threshold NaN, so the sweeps never settle.
+        let a: Matrix<f64, 3, 3> = Matrix::from([[f64::NAN, 1., 0.], [1., 2., 0.], [0., 0., 3.]]);
+        assert_eq!(a.symmetric_eigen(), Err(LinalgError::NoConvergence));
+    }
+
+    fn assert_schur<const N: usize>(a: &Matrix<f64, N, N>) {
+        let schur = a.schur().unwrap();
+        let (q, t) = (schur.q(), schur.t());
+        let mut qt = q;
+        let qt = qt.transpose();
+        assert_close(&(q * t * qt), a, 1e-12);
+        assert_close(&(qt * q), &Matrix::identity(), 1e-12);
+        for i in 0..N {
+            for j in 0..i.saturating_sub(1) {
+                assert_eq!(t[(i, j)], 0.0);
+            }
+        }
+    }
+
+    fn assert_eigenvalues<const N: usize>(a: &Matrix<f64, N, N>, expected: &[Complex<f64>]) {
+        let eigenvalues = a.eigenvalues().unwrap();
+        for value in expected {
+            assert!(
+                eigenvalues.iter().any(|z| (z - value).norm() < 1e-10),
+                "{} not among {:?}",
+                value,
+                eigenvalues
+            );
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_real() {
+        // Companion matrix of (x - 1)(x - 2)(x - 3) = x³ - 6x² + 11x - 6.
+        let a: Matrix<f64, 3, 3> = Matrix::from([[6., -11., 6.], [1., 0., 0.], [0., 1., 0.]]);
+        assert_schur(&a);
+        assert_eigenvalues(
+            &a,
+            &[
+                Complex::new(1., 0.),
+                Complex::new(2., 0.),
+                Complex::new(3., 0.),
+            ],
+        );
+
+        // Triangular matrices are already in Schur form.
+        let t: Matrix<f64, 3, 3> = Matrix::from([[1., 5., -2.], [0., 4., 3.], [0., 0., -7.]]);
+        let eigenvalues = t.eigenvalues().unwrap();
+        assert_eq!(
+            eigenvalues.store,
+            [
+                Complex::new(1., 0.),
+                Complex::new(4., 0.),
+                Complex::new(-7., 0.)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_eigenvalues_complex() {
+        // Companion matrix of (x² + 1)(x - 2) = x³ - 2x² + x - 2.
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., -1., 2.], [1., 0., 0.], [0., 1., 0.]]);
+        assert_schur(&a);
+        assert_eigenvalues(
+            &a,
+            &[
+                Complex::new(0., 1.),
+                Complex::new(0., -1.),
+                Complex::new(2., 0.),
+            ],
+        );
+
+        let schur = a.schur().unwrap();
+        let eigenvalues = schur.eigenvalues();
+        let t = schur.t();
+        let block = (0..2).find(|&i| t[(i + 1, i)] != 0.0).unwrap();
+        assert!(eigenvalues[block].im > 0.0);
+        assert_eq!(eigenvalues[block + 1], eigenvalues[block].conj());
+    }
+
+    #[test]
+    fn test_schur_general() {
+        let a: Matrix<f64, 5, 5> = Matrix::from([
+            [4., -2., 1., 3., 0.],
+            [1., 2., -5., 0., 2.],
+            [0., 3., 1., -1., 4.],
+            [-2., 1., 0., 2., 1.],
+            [3., 0., 2., -4., -1.],
+        ]);
+        assert_schur(&a);
+
+        let eigenvalues = a.eigenvalues().unwrap();
+        let trace: f64 = (0..5).map(|i| a[(i, i)]).sum();
+        let sum: Complex<f64> = eigenvalues.iter().sum();
+        assert!((sum.re - trace).abs() < 1e-12);
+        assert!(sum.im.abs() < 1e-12);
+
+        let product: Complex<f64> = eigenvalues.iter().product();
+        assert!((product.re - a.determinant()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_schur_defective() {
+        // A single Jordan block: every eigenvalue is 2, with one eigenvector.
+        let a: Matrix<f64, 4, 4> = Matrix::from([
+            [2., 1., 0., 0.],
+            [0., 2., 1., 0.],
+            [0., 0., 2., 1.],
+            [0., 0., 0., 2.],
+        ]);
+        assert_schur(&a);
+        assert!(a
+            .eigenvalues()
+            .unwrap()
+            .iter()
+            .all(|z| (z - Complex::new(2., 0.)).norm() < 1e-12));
+
+        let zero: Matrix<f64, 3, 3> = Matrix::zero();
+        assert_schur(&zero);
+        assert_eq!(
+            LinalgError::NoConvergence.to_string(),
+            "iteration did not converge"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod gram_schmidt_tests {
+    use mini_matrix::{gram_schmidt, linear_combination, Vector};
+
+    fn orthonormality_error<const N: usize>(vectors: &[Vector<f64, N>]) -> f64 {
+        let mut error: f64 = 0.0;
+        for (i, u) in vectors.iter().enumerate() {
+            for (j, v) in vectors.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                error = error.max((u.dot(v) - expected).abs());
+            }
+        }
+        error
+    }
+
+    #[test]
+    fn test_orthonormal_and_same_span() {
+        let vectors = [
+            Vector::<f64, 4>::from([1.0, 2.0, 0.0, -1.0]),
+            Vector::from([0.0, 1.0, 3.0, 1.0]),
+            Vector::from([2.0, 0.0, 1.0, 1.0]),
+        ];
+        let basis = gram_schmidt(&vectors, 1e-12, false);
+        assert_eq!(basis.dimension(), 3);
+        assert!(basis.dropped().is_empty());
+        assert!(orthonormality_error(basis.vectors()) < 1e-14);
+
+        // Every input is recovered from its coordinates in the basis.
+        for v in &vectors {
+            let coordinates: Vec<f64> = basis.vectors().iter().map(|q| q.dot(v)).collect();
+            let recovered = linear_combination(basis.vectors(), &coordinates);
+            for i in 0..4 {
+                assert!((recovered[i] - v[i]).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dependent_inputs_are_dropped() {
+        let u = Vector::<f64, 3>::from([1.0, 0.0, 1.0]);
+        let v = Vector::from([0.0, 1.0, 1.0]);
+        let vectors = [
+            Vector::zero(),
+            u,
+            linear_combination(&[u, v], &[2.0, -3.0]),
+            v,
+            u + v,
+            Vector::from([0.0, 0.0, 1.0]),
+        ];
+        let basis = gram_schmidt(&vectors, 1e-10, false);
+        assert_eq!(basis.kept(), &[1, 2, 5]);
+        assert_eq!(basis.dropped(), &[0, 3, 4]);
+        assert_eq!(basis.dimension(), 3);
+        assert!(orthonormality_error(basis.vectors()) < 1e-14);
+    }
+
+    #[test]
+    fn test_tolerance_is_relative() {
+        let scale = 1e-20;
+        let vectors = [
+            Vector::<f64, 2>::from([scale, 0.0]),
+            Vector::from([scale, scale * 1e-6]),
+        ];
+        assert_eq!(gram_schmidt(&vectors, 1e-8, false).dimension(), 2);
+        assert_eq!(gram_schmidt(&vectors, 1e-4, false).dropped(), &[1]);
+    }
+
+    #[test]
+    fn test_reorthogonalization() {
+        // Nearly parallel columns of a Läuchli-type set lose orthogonality in a single pass.
+        let epsilon = 1e-7;
+        let vectors = [
+            Vector::<f64, 4>::from([1.0, epsilon, 0.0, 0.0]),
+            Vector::from([1.0, 0.0, epsilon, 0.0]),
+            Vector::from([1.0, 0.0, 0.0, epsilon]),
+        ];
+        let single = gram_schmidt(&vectors, 1e-14, false);
+        let twice = gram_schmidt(&vectors, 1e-14, true);
+        assert_eq!(single.dimension(), 3);
+        assert_eq!(twice.dimension(), 3);
+        assert!(orthonormality_error(twice.vectors()) < 1e-14);
+        assert!(orthonormality_error(single.vectors()) > 1e-12);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let basis = gram_schmidt::<f64, 3>(&[], 1e-12, true);
+        assert_eq!(basis.dimension(), 0);
+        assert!(basis.kept().is_empty());
+        assert!(basis.dropped().is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod krylov_tests {
+    use mini_matrix::{
+        bicgstab, conjugate_gradient, gmres, CooMatrix, CsrMatrix, DMatrix, DVector, FnOperator,
+        IncompleteCholesky, JacobiPreconditioner, LinalgError, LinearOperator, Matrix,
+        Preconditioner, SolverOptions,
+    };
+
+    /// The five-point Laplacian on a `k x k` grid with Dirichlet boundary conditions.
+    fn poisson_2d(k: usize) -> CsrMatrix<f64> {
+        let mut coo = CooMatrix::new(k * k, k * k);
+        for i in 0..k {
+            for j in 0..k {
+                let row = i * k + j;
+                coo.push(row, row, 4.0).unwrap();
+                if i > 0 {
+                    coo.push(row, row - k, -1.0).unwrap();
+                }
+                if i + 1 < k {
+                    coo.push(row, row + k, -1.0).unwrap();
+                }
+                if j > 0 {
+                    coo.push(row, row - 1, -1.0).unwrap();
+                }
+                if j + 1 < k {
+                    coo.push(row, row + 1, -1.0).unwrap();
+                }
+            }
+        }
+        coo.to_csr()
+    }
+
+    /// Upwinded convection-diffusion in one dimension, which is not symmetric.
+    fn convection_diffusion(n: usize) -> CsrMatrix<f64> {
+        let mut coo = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 3.0).unwrap();
+            if i > 0 {
+                coo.push(i, i - 1, -2.0).unwrap();
+            }
+            if i + 1 < n {
+                coo.push(i, i + 1, -0.5).unwrap();
+            }
+        }
+        coo.to_csr()
+    }
+
+    fn assert_solves<A: LinearOperator<f64>>(a: &A, x: &DVector<f64>, b: &DVector<f64>) {
+        let ax = a.apply(x);
+        let error: f64 = ax
+            .iter()
+            .zip(b.iter())
+            .map(|(p, q)| (p - q) * (p - q))
+            .sum::<f64>()
+            .sqrt();
+        assert!(error <= 1e-8 * b.norm(), "residual {} too large", error);
+    }
+
+    #[test]
+    fn test_conjugate_gradient() {
+        let a = poisson_2d(20);
+        let b = DVector::from(vec![1.0; 400]);
+        let options = SolverOptions::new().tolerance(1e-10);
+        let result = conjugate_gradient(&a, &b, &options).unwrap();
+        assert!(result.converged());
+        assert!(result.iterations() < 100);
+        assert_eq!(result.residual_history().len(), result.iterations() + 1);
+        assert_eq!(result.residual_history()[0], b.norm());
+        assert!(result.residual_norm() <= 1e-10 * b.norm());
+        assert_solves(&a, result.solution(), &b);
+    }
+
+    #[test]
+    fn test_preconditioners_reduce_iterations() {
+        let a = poisson_2d(30);
+        let b = DVector::from((0..900).map(|i| (i % 7) as f64).collect::<Vec<_>>());
+        let options = SolverOptions::new().tolerance(1e-10);
+        let plain = conjugate_gradient(&a, &b, &options).unwrap();
+
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        let preconditioned = conjugate_gradient(&a, &b, &options.preconditioner(&ic)).unwrap();
+        assert!(preconditioned.converged());
+        assert!(preconditioned.iterations() < plain.iterations());
+        assert_solves(&a, preconditioned.solution(), &b);
+
+        // Jacobi undoes a bad row and column scaling of the same system.
+        let scales: Vec<f64> = (0..900).map(|i| 10f64.powi(i % 5)).collect();
+        let scaled = FnOperator::new(900, 900, |x: &DVector<f64>| {
+            let scaled_x = DVector::from(
+                x.iter()
+                    .zip(&scales)
+                    .map(|(x, s)| x * s)
+                    .collect::<Vec<_>>(),
+            );
+            let y = a.apply(&scaled_x);
+            DVector::from(
+                y.iter()
+                    .zip(&scales)
+                    .map(|(y, s)| y * s)
+                    .collect::<Vec<_>>(),
+            )
+        });
+        let jacobi = JacobiPreconditioner::new(&scaled).unwrap();
+        let unscaled = conjugate_gradient(&scaled, &b, &options).unwrap();
+        let rescaled = conjugate_gradient(&scaled, &b, &options.preconditioner(&jacobi)).unwrap();
+        assert!(rescaled.converged());
+        assert!(rescaled.iterations() < unscaled.iterations());
+        assert_solves(&scaled, rescaled.solution(), &b);
+    }
+
+    #[test]
+    fn test_incomplete_cholesky_is_exact_without_fill_in() {
+        let a = Matrix::from([[4.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 4.0]]);
+        let ic = IncompleteCholesky::new(&CsrMatrix::from(a)).unwrap();
+        let b = DVector::from(vec![3.0, 2.0, 3.0]);
+        let x = ic.solve(&b);
+        assert_solves(&a, &x, &b);
+
+        let indefinite = CsrMatrix::from(Matrix::from([[1.0, 2.0], [2.0, 1.0]]));
+        assert_eq!(
+            IncompleteCholesky::new(&indefinite),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        let missing_diagonal = CsrMatrix::from(Matrix::from([[1.0, 0.0], [0.0, 0.0]]));
+        assert_eq!(
+            IncompleteCholesky::new(&missing_diagonal),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        let rectangular = CsrMatrix::from(Matrix::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]));
+        assert_eq!(
+            IncompleteCholesky::new(&rectangular),
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_gmres() {
+        let a = convection_diffusion(200);
+        let b = DVector::from(vec![1.0; 200]);
+        let result = gmres(&a, &b, &SolverOptions::new().tolerance(1e-10)).unwrap();
+        assert!(result.converged());
+        assert_solves(&a, result.solution(), &b);
+        let history = result.residual_history();
+        assert!(history
+            .windows(2)
+            .all(|pair| pair[1] <= pair[0] * (1.0 + 1e-12)));
+
+        // A short restart needs more iterations but still converges.
+        let restarted = gmres(&a, &b, &SolverOptions::new().tolerance(1e-10).restart(5)).unwrap();
+        assert!(restarted.converged());
+        assert!(restarted.iterations() >= result.iterations());
+        assert_solves(&a, restarted.solution(), &b);
+
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        let options = SolverOptions::new()
+            .tolerance(1e-10)
+            .preconditioner(&jacobi);
+        let preconditioned = gmres(&a, &b, &options).unwrap();
+        assert!(preconditioned.converged());
+        assert_solves(&a, preconditioned.solution(), &b);
+    }
+
+    #[test]
+    fn test_bicgstab() {
+        let a = convection_diffusion(200);
+        let b = DVector::from((0..200).map(|i| (i as f64).sin()).collect::<Vec<_>>());
+        let result = bicgstab(&a, &b, &SolverOptions::new().tolerance(1e-10)).unwrap();
+        assert!(result.converged());
+        assert_eq!(result.residual_history().len(), result.iterations() + 1);
+        assert_solves(&a, result.solution(), &b);
+
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        let options = SolverOptions::new()
+            .tolerance(1e-10)
+            .preconditioner(&jacobi);
+        let preconditioned = bicgstab(&a, &b, &options).unwrap();
+        assert!(preconditioned.converged());
+        assert_solves(&a, preconditioned.solution(), &b);
+    }
+
+    fn check_all_solvers<A: LinearOperator<f64>>(a: &A, b: &DVector<f64>) {
+        let options = SolverOptions::new().tolerance(1e-12);
+        for result in [
+            conjugate_gradient(a, b, &options).unwrap(),
+            gmres(a, b, &options).unwrap(),
+            bicgstab(a, b, &options).unwrap(),
+        ] {
+            assert!(result.converged());
+            for &value in result.solution().iter() {
+                assert!((value - 1.0).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dense_and_closure_operators() {
+        let fixed = Matrix::from([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let dynamic = DMatrix::from(fixed);
+        let closure = FnOperator::new(3, 3, |x: &DVector<f64>| {
+            DVector::from(vec![
+                4.0 * x[0] + x[1],
+                x[0] + 3.0 * x[1] + x[2],
+                x[1] + 2.0 * x[2],
+            ])
+        });
+        assert_eq!(closure.diagonal().store, vec![4.0, 3.0, 2.0]);
+        let b = DVector::from(vec![5.0, 5.0, 3.0]);
+        check_all_solvers(&fixed, &b);
+        check_all_solvers(&dynamic, &b);
+        check_all_solvers(&closure, &b);
+    }
+
+    #[test]
+    fn test_initial_guess_and_zero_rhs() {
+        let a = poisson_2d(5);
+        let exact = DVector::from(vec![1.0; 25]);
+        let b = a.apply(&exact);
+        let result =
+            conjugate_gradient(&a, &b, &SolverOptions::new().initial_guess(&exact)).unwrap();
+        assert!(result.converged());
+        assert_eq!(result.iterations(), 0);
+        assert_eq!(result.solution(), &exact);
+
+        let zero = DVector::zero(25);
+        let result = gmres(&a, &zero, &SolverOptions::new()).unwrap();
+        assert!(result.converged());
+        assert_eq!(result.solution(), &zero);
+    }
+
+    #[test]
+    fn test_iteration_limit() {
+        let a = poisson_2d(20);
+        let b = DVector::from(vec![1.0; 400]);
+        let options = SolverOptions::new().tolerance(1e-12).max_iterations(3);
+        for result in [
+            conjugate_gradient(&a, &b, &options).unwrap(),
+            gmres(&a, &b, &options).unwrap(),
+            bicgstab(&a, &b, &options).unwrap(),
+        ] {
+            assert!(!result.converged());
+            assert_eq!(result.iterations(), 3);
+            assert_eq!(result.residual_history().len(), 4);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let b = DVector::from(vec![1.0, 1.0]);
+        let options = SolverOptions::new();
+
+        let rectangular = Matrix::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        assert_eq!(
+            gmres(&rectangular, &b, &options),
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 })
+        );
+        let a = Matrix::from([[2.0, 0.0], [0.0, 1.0]]);
+        assert_eq!(
+            bicgstab(&a, &DVector::from(vec![1.0]), &options),
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            })
+        );
+        let guess = DVector::zero(3);
+        assert_eq!(
+            conjugate_gradient(&a, &b, &options.initial_guess(&guess)),
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+
+        let indefinite = Matrix::from([[1.0, 0.0], [0.0, -1.0]]);
+        assert_eq!(
+            conjugate_gradient(&indefinite, &DVector::from(vec![0.0, 1.0]), &options),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        let zero_diagonal = Matrix::from([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(
+            JacobiPreconditioner::new(&zero_diagonal),
+            Err(LinalgError::Singular)
+        );
+    }
+}
@@ -0,0 +1,109 @@
+mod common;
+
+#[cfg(test)]
+mod least_squares_tests {
+    use crate::common::assert_close;
+    use mini_matrix::{Matrix, Vector};
+
+    fn assert_penrose<const M: usize, const N: usize>(a: &Matrix<f64, M, N>) {
+        let pinv = a.pseudo_inverse().unwrap();
+        let a_pinv = *a * pinv;
+        let pinv_a = pinv * *a;
+        assert_close(&(a_pinv * *a), a, 1e-12);
+        assert_close(&(pinv_a * pinv), &pinv, 1e-12);
+        let (mut left, mut right) = (a_pinv, pinv_a);
+        assert_close(&left.transpose(), &a_pinv, 1e-12);
+        assert_close(&right.transpose(), &pinv_a, 1e-12);
+    }
+
+    #[test]
+    fn test_pseudo_inverse_square() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., 1., 1.], [1., 3., 2.], [1., 0., 0.]]);
+        assert_close(&a.pseudo_inverse().unwrap(), &a.inverse().unwrap(), 1e-12);
+        assert_penrose(&a);
+    }
+
+    #[test]
+    fn test_pseudo_inverse_tall() {
+        let a: Matrix<f64, 3, 2> = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+        assert_penrose(&a);
+
+        let mut transposed = a;
+        let at = transposed.transpose();
+        let left_inverse = (at * a).inverse().unwrap() * at;
+        assert_close(&a.pseudo_inverse().unwrap(), &left_inverse, 1e-12);
+        assert_close(
+            &(a.pseudo_inverse().unwrap() * a),
+            &Matrix::identity(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_pseudo_inverse_wide() {
+        let a: Matrix<f64, 2, 4> = Matrix::from([[1., 0., 2., -1.], [0., 1., 1., 3.]]);
+        assert_penrose(&a);
+        assert_close(
+            &(a * a.pseudo_inverse().unwrap()),
+            &Matrix::identity(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_pseudo_inverse_rank_deficient() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 1., 1.]]);
+        assert_penrose(&a);
+
+        let zero: Matrix<f64, 2, 3> = Matrix::zero();
+        assert_eq!(zero.pseudo_inverse(), Ok(Matrix::zero()));
+    }
+
+    #[test]
+    fn test_least_squares_overdetermined() {
+        let a: Matrix<f64, 4, 2> = Matrix::from([[1., 1.], [1., 2.], [1., 3.], [1., 4.]]);
+        let b = Vector::from([6., 5., 7., 10.]);
+        let fit = a.least_squares(&b).unwrap();
+        assert_eq!(fit.rank(), 2);
+        assert!((fit.solution()[0] - 3.5).abs() < 1e-12);
+        assert!((fit.solution()[1] - 1.4).abs() < 1e-12);
+
+        let residual = fit.residual();
+        let expected = [1.1, -1.3, -0.7, 0.9];
+        for i in 0..4 {
+            assert!((residual[i] - expected[i]).abs() < 1e-12);
+        }
+        assert!((fit.residual_norm() - 4.2_f64.sqrt()).abs() < 1e-12);
+
+        // The residual is orthogonal to the column space.
+        for j in 0..2 {
+            let dot: f64 = (0..4).map(|i| a[(i, j)] * residual[i]).sum();
+            assert!(dot.abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_least_squares_underdetermined() {
+        let a: Matrix<f64, 2, 3> = Matrix::from([[1., 2., 0.], [0., 1., 1.]]);
+        let b = Vector::from([3., 2.]);
+        let fit = a.least_squares(&b).unwrap();
+        assert_eq!(fit.rank(), 2);
+        assert!(fit.residual_norm() < 1e-12);
+
+        // The minimum-norm solution lies in the row space: x = Aᵀ y for some y.
+        let x = fit.solution();
+        let y = [x[0], x[2]];
+        assert!((x[1] - (2.0 * y[0] + y[1])).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_least_squares_rank_deficient() {
+        // Both columns are the same, so every split of the coefficient fits equally well.
+        let a: Matrix<f64, 3, 2> = Matrix::from([[1., 1.], [2., 2.], [3., 3.]]);
+        let fit = a.least_squares(&Vector::from([2., 4., 7.])).unwrap();
+        assert_eq!(fit.rank(), 1);
+        let x = fit.solution();
+        assert!((x[0] - x[1]).abs() < 1e-12);
+        assert!((x[0] + x[1] - 31.0 / 14.0).abs() < 1e-12);
+    }
+}
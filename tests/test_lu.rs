@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod lu_tests {
+    use mini_matrix::{LinalgError, Matrix, Vector};
+
+    #[test]
+    fn test_lu_factors() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., 1., 1.], [4., -6., 0.], [-2., 7., 2.]]);
+        let lu = a.lu();
+
+        let mut l = lu.l();
+        let mut p = lu.p();
+        let lhs = p.mul_mat(&a);
+        let rhs = l.mul_mat(&lu.u());
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((lhs[(i, j)] - rhs[(i, j)]).abs() < 1e-12);
+            }
+            for j in i + 1..3 {
+                assert_eq!(l[(i, j)], 0.);
+                assert_eq!(lu.u()[(j, i)], 0.);
+            }
+            assert_eq!(l[(i, i)], 1.);
+        }
+        assert_eq!(lu.permutation(), [1, 0, 2]);
+        assert_eq!(lu.sign(), -1.);
+    }
+
+    #[test]
+    fn test_lu_determinant() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., -5., 0.], [4., 3., 7.], [-2., 3., 4.]]);
+        assert!((a.lu().determinant() - 132.).abs() < 1e-10);
+
+        let a: Matrix<f64, 2, 2> = Matrix::from([[0., 1.], [1., 0.]]);
+        assert_eq!(a.lu().sign(), -1.);
+        assert_eq!(a.lu().determinant(), -1.);
+
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(a.lu().determinant(), 0.);
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., 1., 1.], [4., -6., 0.], [-2., 7., 2.]]);
+        let lu = a.lu();
+
+        let x = lu.solve(&Vector::from([5., -2., 9.])).unwrap();
+        let expected = [1., 1., 2.];
+        for i in 0..3 {
+            assert!((x[i] - expected[i]).abs() < 1e-12);
+        }
+
+        // The same factorization is reused for a second right-hand side.
+        let x = lu.solve(&Vector::from([2., 4., -2.])).unwrap();
+        let expected = [1., 0., 0.];
+        for i in 0..3 {
+            assert!((x[i] - expected[i]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_lu_solve_matrix() {
+        let a: Matrix<f64, 2, 2> = Matrix::from([[4., 3.], [6., 3.]]);
+        let b: Matrix<f64, 2, 3> = Matrix::from([[10., 1., 0.], [12., 0., 1.]]);
+        let x = a.lu().solve_matrix(&b).unwrap();
+        let expected = [[1., -0.5, 0.5], [2., 1., -2. / 3.]];
+        for i in 0..2 {
+            for j in 0..3 {
+                assert!((x[(i, j)] - expected[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_inverse() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 3.], [0., 1., 4.], [5., 6., 0.]]);
+        let inverse = a.lu().inverse().unwrap();
+        let expected = [[-24., 18., 5.], [20., -15., -4.], [-5., 4., 1.]];
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((inverse[(i, j)] - expected[i][j]).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        assert!(lu.solve(&Vector::from([1., 2., 3.])).is_err());
+        assert!(lu.inverse().is_err());
+
+        let a = Matrix::<f64, 3, 3>::zero();
+        assert!(a.lu().is_singular());
+        assert_eq!(a.lu().determinant(), 0.);
+    }
+
+    #[test]
+    fn test_lu_rcond() {
+        let a: Matrix<f64, 3, 3> = Matrix::identity();
+        assert_eq!(a.lu().rcond(), 1.);
+
+        // ‖A‖₁ = ‖A⁻¹‖₁ = 7, so rcond = 1 / 49.
+        let a: Matrix<f64, 2, 2> = Matrix::from([[4., 3.], [3., 2.]]);
+        assert!((a.lu().rcond() - 1. / 49.).abs() < 1e-12);
+
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(a.lu().rcond(), 0.);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., 1., -1.], [-3., -1., 2.], [-2., 1., 2.]]);
+        let x = a.solve(&Vector::from([8., -11., -3.])).unwrap();
+        let expected = [2., 3., -1.];
+        for i in 0..3 {
+            assert!((x[i] - expected[i]).abs() < 1e-12);
+        }
+
+        let mut a: Matrix<f64, 6, 6> = Matrix::zero();
+        for i in 0..6 {
+            a[(i, i)] = 2.;
+            if i > 0 {
+                a[(i, i - 1)] = -1.;
+                a[(i - 1, i)] = -1.;
+            }
+        }
+        let x = a.solve(&Vector::from([1., 0., 0., 0., 0., 1.])).unwrap();
+        for i in 0..6 {
+            assert!((x[i] - 1.).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        assert_eq!(
+            a.solve(&Vector::from([1., 2., 3.])),
+            Err(LinalgError::Singular)
+        );
+
+        let a: Matrix<f64, 2, 2> = Matrix::zero();
+        assert_eq!(a.solve(&Vector::from([1., 2.])), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_solve_ill_conditioned() {
+        // The 12x12 Hilbert matrix has a condition number around 1e16.
+        let mut a: Matrix<f64, 12, 12> = Matrix::zero();
+        for i in 0..12 {
+            for j in 0..12 {
+                a[(i, j)] = 1. / (i + j + 1) as f64;
+            }
+        }
+        match a.solve(&Vector::from([1.; 12])) {
+            Err(LinalgError::IllConditioned { rcond }) => assert!(rcond < f64::EPSILON),
+            other => panic!("expected an ill-conditioned error, got {:?}", other),
+        }
+        // The factorization itself still solves the system when asked directly.
+        assert!(a.lu().solve(&Vector::from([1.; 12])).is_ok());
+    }
+
+    #[test]
+    fn test_solve_matrix() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 3.], [0., 1., 4.], [5., 6., 0.]]);
+        let b: Matrix<f64, 3, 2> = Matrix::from([[14., 1.], [14., 0.], [17., 0.]]);
+        let x = a.solve_matrix(&b).unwrap();
+        let expected = [[1., -24.], [2., 20.], [3., -5.]];
+        for i in 0..3 {
+            for j in 0..2 {
+                assert!((x[(i, j)] - expected[i][j]).abs() < 1e-10);
+            }
+        }
+
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 1.], [1., 1.]]);
+        let b: Matrix<f64, 2, 2> = Matrix::identity();
+        assert_eq!(a.solve_matrix(&b), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_linalg_error_display() {
+        assert_eq!(LinalgError::Singular.to_string(), "matrix is singular");
+        assert_eq!(
+            LinalgError::IllConditioned { rcond: 1e-20 }.to_string(),
+            "matrix is ill-conditioned (rcond = 1e-20)"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod matrix_tests {
-    use mini_matrix::{Matrix, Vector};
+    use mini_matrix::{LinalgError, Matrix, Vector};
 
     #[test]
     fn test_from() {
@@ -228,6 +228,75 @@ mod matrix_tests {
         assert_eq!(u.mul_mat(&v).store, [[-14., -7.], [44., 22.]]); // [2., 1.] [44., 22.]
     }
 
+    #[test]
+    fn test_from_vecs() {
+        let a = Matrix::<i32, 2, 3>::from_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(a, Ok(Matrix::from([[1, 2, 3], [4, 5, 6]])));
+
+        let short = Matrix::<i32, 2, 3>::from_vecs(vec![vec![1, 2, 3]]);
+        assert_eq!(
+            short,
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 3),
+                found: (1, 3)
+            })
+        );
+
+        let long_row = Matrix::<i32, 2, 2>::from_vecs(vec![vec![1, 2], vec![3, 4, 5]]);
+        assert_eq!(
+            long_row,
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_mul_vec_rectangular() {
+        let mut tall = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        let v = Vector::from([1, -1]);
+        assert_eq!(tall.mul_vec(&v), Vector::from([-1, -1, -1]));
+        assert_eq!(tall * v, Vector::from([-1, -1, -1]));
+
+        let mut wide = Matrix::from([[1., 0., 2., -1.], [3., 1., 0., 2.]]);
+        let v = Vector::from([2., 1., 0.5, 1.]);
+        assert_eq!(wide.mul_vec(&v), Vector::from([2., 9.]));
+        assert_eq!(wide * v, Vector::from([2., 9.]));
+    }
+
+    #[test]
+    fn test_mul_mat_rectangular() {
+        let mut a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[7, 8], [9, 10], [11, 12]]);
+        let expected = Matrix::from([[58, 64], [139, 154]]);
+        assert_eq!(a.mul_mat(&b), expected);
+        assert_eq!(a * b, expected);
+
+        let mut b = b;
+        assert_eq!(
+            b.mul_mat(&a),
+            Matrix::from([[39, 54, 69], [49, 68, 87], [59, 82, 105]])
+        );
+
+        let mut row = Matrix::from([[1, 2, 3]]);
+        let column = Matrix::from([[4], [5], [6]]);
+        assert_eq!(row.mul_mat(&column), Matrix::from([[32]]));
+        assert_eq!(
+            column * row,
+            Matrix::from([[4, 8, 12], [5, 10, 15], [6, 12, 18]])
+        );
+    }
+
+    #[test]
+    fn test_mul_operator_square() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[5, 6], [7, 8]]);
+        assert_eq!((a * b).store, [[19, 22], [43, 50]]);
+        assert_eq!((b * a).store, [[23, 34], [31, 46]]);
+        assert_eq!(a * Matrix::identity(), a);
+    }
+
     #[test]
     fn test_trace() {
         let m = Matrix::from([[2., 3.], [5., 7.]]);
@@ -333,7 +402,7 @@ mod matrix_tests {
         let result: f32 = u.row_echelon().store.as_flattened().iter().sum();
 
         let expected: f32 = Matrix::from([
-            [1.0, 0.625, 0.0, 0.0, -12.1666667],
+            [1.0, 0.625, 0.0, 0.0, -12.166_667],
             [0.0, 0.0, 1.0, 0.0, -3.6666667],
             [0.0, 0.0, 0.0, 1.0, 29.5],
         ] as [[f32; 5]; 3])
@@ -341,7 +410,7 @@ mod matrix_tests {
         .as_flattened()
         .iter()
         .sum();
-        assert_eq!((result - expected).abs() < 0.0001, true);
+        assert!((result - expected).abs() < 0.0001);
         // [1.0, 0.625, 0.0, 0.0, -12.1666667]
         // [0.0, 0.0, 1.0, 0.0, -3.6666667]
         // [0.0, 0.0, 0.0, 1.0, 29.5 ]
@@ -424,6 +493,69 @@ mod matrix_tests {
         assert_eq!(mat8.determinant(), 1);
     }
 
+    #[test]
+    fn test_determinant_large() {
+        // Tridiagonal [-1, 2, -1] matrices have determinant n + 1.
+        let mut u = Matrix::<f64, 6, 6>::zero();
+        for i in 0..6 {
+            u[(i, i)] = 2.;
+            if i > 0 {
+                u[(i, i - 1)] = -1.;
+                u[(i - 1, i)] = -1.;
+            }
+        }
+        assert!((u.determinant() - 7.).abs() < 1e-10);
+
+        let mut u = Matrix::<f64, 8, 8>::zero();
+        for i in 0..8 {
+            u[(i, i)] = 2.;
+            if i > 0 {
+                u[(i, i - 1)] = -1.;
+                u[(i - 1, i)] = -1.;
+            }
+        }
+        assert!((u.determinant() - 9.).abs() < 1e-10);
+
+        let u = Matrix::from([
+            [2., 1., 3., 4., 1.],
+            [4., 2., 6., 8., 2.],
+            [1., 0., 1., 0., 1.],
+            [3., 3., 0., 1., 2.],
+            [5., 1., 2., 2., 0.],
+        ]);
+        assert_eq!(u.determinant(), 0.);
+    }
+
+    #[test]
+    fn test_determinant_pivoting() {
+        let u = Matrix::from([[0., 1., 2.], [1., 0., 3.], [4., -3., 8.]]);
+        assert_eq!(u.determinant(), -2.);
+
+        let u = Matrix::from([[0., 0., 1.], [0., 1., 0.], [1., 0., 0.]]);
+        assert_eq!(u.determinant(), -1.);
+
+        let u = Matrix::from([[0., 2.], [3., 0.]]);
+        assert_eq!(u.determinant(), -6.);
+    }
+
+    #[test]
+    fn test_determinant_integer() {
+        let u = Matrix::from([
+            [3, 2, 0, 1, 4],
+            [4, 0, 1, 2, 3],
+            [3, 0, 2, 1, 2],
+            [9, 2, 3, 1, 1],
+            [1, 1, 1, 1, 1],
+        ]);
+        assert_eq!(u.determinant(), -96);
+
+        let u = Matrix::from([[0, 1], [1, 0]]);
+        assert_eq!(u.determinant(), -1);
+
+        let u = Matrix::<i64, 0, 0>::zero();
+        assert_eq!(u.determinant(), 1);
+    }
+
     #[test]
     fn test_inverse_identity_2x2() {
         let matrix = Matrix::from([[1.0, 0.0], [0.0, 1.0]]);
@@ -454,9 +586,14 @@ mod matrix_tests {
 
     #[test]
     fn test_inverse_non_trivial_2x2() {
-        let matrix = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let matrix: Matrix<f64, 2, 2> = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
         let expected = Matrix::from([[-2.0, 1.0], [1.5, -0.5]]);
-        assert_eq!(matrix.inverse().unwrap(), expected);
+        let inverse = matrix.inverse().unwrap();
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((inverse[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
     }
 
     #[test]
@@ -466,6 +603,68 @@ mod matrix_tests {
         assert_eq!(matrix.inverse().unwrap(), expected);
     }
 
+    #[test]
+    fn test_inverse_transform_4x4() {
+        // Rotation by 90 degrees about z followed by a translation of (1, 2, 3).
+        let matrix = Matrix::from([
+            [0.0, -1.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 2.0],
+            [0.0, 0.0, 1.0, 3.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let expected = Matrix::from([
+            [0.0, 1.0, 0.0, -2.0],
+            [-1.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0, -3.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        assert_eq!(matrix.inverse().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_inverse_6x6() {
+        let mut matrix = Matrix::<f64, 6, 6>::zero();
+        for i in 0..6 {
+            for j in 0..6 {
+                matrix[(i, j)] = 1.0 / (1.0 + i as f64 + 2.0 * j as f64);
+            }
+            matrix[(i, i)] += 2.0;
+        }
+        let mut product = matrix.inverse().unwrap();
+        let identity = product.mul_mat(&matrix);
+        for i in 0..6 {
+            for j in 0..6 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((identity[(i, j)] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let matrix = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(matrix.inverse(), Err(LinalgError::Singular));
+
+        // Rounding noise must not make a singular matrix look invertible.
+        let matrix = Matrix::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]);
+        assert_eq!(matrix.inverse(), Err(LinalgError::Singular));
+
+        let matrix = Matrix::<f64, 3, 3>::zero();
+        assert_eq!(matrix.inverse(), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_inverse_with_tolerance() {
+        let matrix: Matrix<f64, 2, 2> = Matrix::from([[1e-8, 0.0], [0.0, 1e-8]]);
+        assert_eq!(
+            matrix.inverse_with_tolerance(1e-6),
+            Err(LinalgError::Singular)
+        );
+        let inverse = matrix.inverse_with_tolerance(1e-10).unwrap();
+        assert!((inverse[(0, 0)] - 1e8).abs() < 1e-4);
+        assert!(matrix.inverse().is_ok());
+    }
+
     #[test]
     fn test_rank_zeros() {
         let matrix: Matrix<f64, 2, 2> = Matrix::from([[0.0, 0.0], [0.0, 0.0]]);
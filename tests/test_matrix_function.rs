@@ -0,0 +1,143 @@
+mod common;
+
+#[cfg(test)]
+mod matrix_function_tests {
+    use crate::common::assert_close;
+    use mini_matrix::{LinalgError, Matrix};
+
+    fn rotation(theta: f64) -> Matrix<f64, 2, 2> {
+        Matrix::from([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]])
+    }
+
+    fn generator(theta: f64) -> Matrix<f64, 2, 2> {
+        Matrix::from([[0.0, -theta], [theta, 0.0]])
+    }
+
+    #[test]
+    fn test_exp_of_rotation_generators() {
+        for theta in [1e-3, 0.5, 1.0, 3.0, 10.0, 100.0] {
+            assert_close(&generator(theta).exp().unwrap(), &rotation(theta), 1e-12);
+        }
+
+        // The generator of rotations about the z axis in three dimensions.
+        let theta = 2.0;
+        let g = Matrix::<f64, 3, 3>::from([[0.0, -theta, 0.0], [theta, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+        let expected = Matrix::from([
+            [theta.cos(), -theta.sin(), 0.0],
+            [theta.sin(), theta.cos(), 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        assert_close(&g.exp().unwrap(), &expected, 1e-14);
+    }
+
+    #[test]
+    fn test_exp_closed_forms() {
+        let diagonal =
+            Matrix::<f64, 3, 3>::from([[1.0, 0.0, 0.0], [0.0, -2.0, 0.0], [0.0, 0.0, 5.0]]);
+        let expected = Matrix::from([
+            [1f64.exp(), 0.0, 0.0],
+            [0.0, (-2f64).exp(), 0.0],
+            [0.0, 0.0, 5f64.exp()],
+        ]);
+        assert_close(&diagonal.exp().unwrap(), &expected, 1e-14);
+
+        // A Jordan block: e^(λI + N) = e^λ (I + N + N² / 2).
+        let lambda = 0.7;
+        let jordan =
+            Matrix::<f64, 3, 3>::from([[lambda, 1.0, 0.0], [0.0, lambda, 1.0], [0.0, 0.0, lambda]]);
+        let e = lambda.exp();
+        let expected = Matrix::from([[e, e, e / 2.0], [0.0, e, e], [0.0, 0.0, e]]);
+        assert_close(&jordan.exp().unwrap(), &expected, 1e-14);
+
+        assert_eq!(Matrix::<f64, 2, 2>::zero().exp(), Ok(Matrix::identity()));
+    }
+
+    #[test]
+    fn test_exp_solves_linear_system() {
+        // x'' = -x as a first-order system: x(t) = cos t, x'(t) = -sin t from x(0) = 1, x'(0) = 0.
+        let t = 1.3;
+        let a = Matrix::<f64, 2, 2>::from([[0.0, t], [-t, 0.0]]);
+        let flow = a.exp().unwrap();
+        assert!((flow[(0, 0)] - t.cos()).abs() < 1e-14);
+        assert!((flow[(1, 0)] + t.sin()).abs() < 1e-14);
+
+        // e^A e^(-A) = I.
+        let b = Matrix::<f64, 3, 3>::from([[0.3, -1.2, 2.0], [0.5, 0.1, -0.7], [-1.1, 0.4, 0.9]]);
+        let mut negated = b;
+        negated.scl(-1.0);
+        assert_close(
+            &(b.exp().unwrap() * negated.exp().unwrap()),
+            &Matrix::identity(),
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn test_exp_extreme_norms() {
+        let overflowing = Matrix::<f64, 2, 2>::from([[800.0, 0.0], [0.0, 1.0]]);
+        assert_eq!(overflowing.exp(), Err(LinalgError::Overflow));
+        let infinite = Matrix::<f64, 2, 2>::from([[f64::INFINITY, 0.0], [0.0, 1.0]]);
+        assert_eq!(infinite.exp(), Err(LinalgError::Overflow));
+        assert!(Matrix::<f64, 1, 1>::from([[f64::NAN]]).exp().unwrap()[(0, 0)].is_nan());
+
+        // Scaling by 2^-s with s above 1024 would underflow a single factor to zero.
+        let decaying = Matrix::<f64, 2, 2>::from([[-f64::MAX, 0.0], [0.0, -1e300]]);
+        assert_eq!(decaying.exp(), Ok(Matrix::zero()));
+        let small = Matrix::<f64, 1, 1>::from([[-700.0]]);
+        let e = small.exp().unwrap()[(0, 0)];
+        assert!((e / (-700f64).exp() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        // The square root of a rotation by θ is the rotation by θ / 2.
+        for theta in [0.1, 1.0, 2.5, 3.1] {
+            assert_close(
+                &rotation(theta).sqrt().unwrap(),
+                &rotation(theta / 2.0),
+                1e-12,
+            );
+        }
+
+        let a = Matrix::<f64, 3, 3>::from([[4.0, 1.0, 0.0], [1.0, 5.0, 2.0], [0.0, 2.0, 6.0]]);
+        let mut root = a.sqrt().unwrap();
+        assert_close(&(root * root), &a, 1e-13);
+        assert_close(&root.transpose(), &root, 1e-13);
+
+        let diagonal = Matrix::<f64, 2, 2>::from([[1e-6, 0.0], [0.0, 1e6]]);
+        assert_close(
+            &diagonal.sqrt().unwrap(),
+            &Matrix::from([[1e-3, 0.0], [0.0, 1e3]]),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_sqrt_failures() {
+        let singular = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.sqrt(), Err(LinalgError::Singular));
+        let negative = Matrix::<f64, 2, 2>::from([[-4.0, 0.0], [0.0, 1.0]]);
+        assert!(negative.sqrt().is_err());
+        assert!(negative.log().is_err());
+    }
+
+    #[test]
+    fn test_log() {
+        // The logarithm of a rotation by |θ| < π is its generator.
+        for theta in [1e-4, 0.5, 2.0, 3.0] {
+            assert_close(&rotation(theta).log().unwrap(), &generator(theta), 1e-11);
+        }
+
+        let a = Matrix::<f64, 3, 3>::from([[4.0, 1.0, 0.0], [1.0, 5.0, 2.0], [0.0, 2.0, 6.0]]);
+        assert_close(&a.log().unwrap().exp().unwrap(), &a, 1e-12);
+
+        let b = Matrix::<f64, 3, 3>::from([[0.3, -1.2, 2.0], [0.5, 0.1, -0.7], [-1.1, 0.4, 0.9]]);
+        assert_close(&b.exp().unwrap().log().unwrap(), &b, 1e-11);
+
+        assert_eq!(Matrix::<f64, 3, 3>::identity().log(), Ok(Matrix::zero()));
+        let scalar = Matrix::<f64, 2, 2>::from([[1e6, 0.0], [0.0, 1e-6]]);
+        let log = scalar.log().unwrap();
+        assert!((log[(0, 0)] - 1e6f64.ln()).abs() < 1e-9);
+        assert!((log[(1, 1)] - 1e-6f64.ln()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod norm_tests {
+    use mini_matrix::{Matrix, Vector};
+
+    fn hilbert<const N: usize>() -> Matrix<f64, N, N> {
+        let mut h = Matrix::zero();
+        for i in 0..N {
+            for j in 0..N {
+                h[(i, j)] = 1.0 / (i + j + 1) as f64;
+            }
+        }
+        h
+    }
+
+    #[test]
+    fn test_norms_of_rectangular_matrix() {
+        let a = Matrix::<f64, 3, 2>::from([[1.0, -7.0], [-2.0, -3.0], [4.0, 0.5]]);
+        assert_eq!(a.norm_1(), 10.5);
+        assert_eq!(a.norm_inf(), 8.0);
+        assert_eq!(a.max_norm(), 7.0);
+        assert!((a.frobenius_norm() - 79.25f64.sqrt()).abs() < 1e-14);
+
+        // The norms of the transpose swap the induced 1- and ∞-norms.
+        let mut b = a;
+        let t = b.transpose();
+        assert_eq!(t.norm_1(), a.norm_inf());
+        assert_eq!(t.norm_inf(), a.norm_1());
+        assert_eq!(t.frobenius_norm(), a.frobenius_norm());
+    }
+
+    #[test]
+    fn test_norm_inequalities() {
+        let a = Matrix::<f64, 3, 3>::from([[2.0, -1.0, 0.5], [0.0, 3.0, -4.0], [1.0, 1.0, 1.0]]);
+        let spectral = a.spectral_norm().unwrap();
+        let frobenius = a.frobenius_norm();
+        assert!(a.max_norm() <= spectral + 1e-12);
+        assert!(spectral <= frobenius + 1e-12);
+        assert!(frobenius <= 3f64.sqrt() * spectral + 1e-12);
+        assert!(spectral * spectral <= a.norm_1() * a.norm_inf() + 1e-12);
+
+        // Every induced norm bounds the growth of A * x in the matching vector norm.
+        let x = Vector::from([0.3, -1.2, 2.0]);
+        let y = a * x;
+        assert!(y.norm_1() <= a.norm_1() * x.norm_1() + 1e-12);
+        assert!(y.norm_inf() <= a.norm_inf() * x.norm_inf() + 1e-12);
+        assert!(y.norm() <= spectral * x.norm() + 1e-12);
+    }
+
+    #[test]
+    fn test_frobenius_is_scaled() {
+        let big = Matrix::<f64, 2, 2>::from([[3e200, 0.0], [0.0, 4e200]]);
+        assert!((big.frobenius_norm() / 5e200 - 1.0).abs() < 1e-14);
+        let tiny = Matrix::<f64, 2, 2>::from([[3e-200, 0.0], [4e-200, 0.0]]);
+        assert!((tiny.frobenius_norm() / 5e-200 - 1.0).abs() < 1e-14);
+        assert_eq!(Matrix::<f64, 2, 3>::zero().frobenius_norm(), 0.0);
+        assert_eq!(Matrix::<f64, 0, 0>::zero().norm_1(), 0.0);
+    }
+
+    #[test]
+    fn test_nan_propagates() {
+        let a = Matrix::<f64, 2, 2>::from([[1.0, f64::NAN], [-5.0, 2.0]]);
+        assert!(a.norm_1().is_nan());
+        assert!(a.norm_inf().is_nan());
+        assert!(a.max_norm().is_nan());
+        assert!(a.frobenius_norm().is_nan());
+    }
+
+    #[test]
+    fn test_condition_number_estimate() {
+        assert_eq!(
+            Matrix::<f64, 4, 4>::identity().condition_number_estimate(),
+            1.0
+        );
+
+        // The 1-norm condition number of the 6 x 6 Hilbert matrix is about 2.9e7.
+        let h = hilbert::<6>();
+        let exact = h.norm_1() * h.inverse().unwrap().norm_1();
+        let estimate = h.condition_number_estimate();
+        assert!(estimate <= exact * (1.0 + 1e-6));
+        assert!(estimate >= exact / 10.0);
+        assert!(exact > 1e7 && exact < 1e8);
+
+        // The 2-norm condition number from the SVD is within a factor N of the 1-norm one.
+        let svd = h.condition_number().unwrap();
+        assert!(svd <= 6.0 * exact && exact <= 6.0 * svd);
+
+        let singular =
+            Matrix::<f64, 3, 3>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert!(singular.condition_number_estimate() > 1e15);
+    }
+}
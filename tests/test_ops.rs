@@ -1,3 +1,4 @@
+#[allow(clippy::float_equality_without_abs)]
 mod ops_tests {
     use ::mini_matrix::{angle_cos, cross_product, linear_combination, Vector};
 
@@ -12,11 +13,11 @@ mod ops_tests {
         let v2 = Vector::from([0., 10., -100.]);
 
         let expected = Vector::from([10., -2., 0.5]);
-        let result = linear_combination(&mut [e1, e2, e3], &[10., -2., 0.5]);
+        let result = linear_combination(&[e1, e2, e3], &[10., -2., 0.5]);
         assert_eq!(result.store, expected.store); // [10.] [-2.]  [0.5]
 
         let expected = Vector::from([10., 0., 230.]);
-        let result = linear_combination(&mut [v1, v2], &[10., -2.]);
+        let result = linear_combination(&[v1, v2], &[10., -2.]);
         assert_eq!(result.store, expected.store); // [10.][0.][230.]
     }
 
@@ -25,14 +26,14 @@ mod ops_tests {
         let v1 = Vector::from([-42.0, 42.0]);
         let scalars1 = [-1.0];
         assert_eq!(
-            linear_combination(&mut [v1], &scalars1),
+            linear_combination(&[v1], &scalars1),
             Vector::from([42.0, -42.0])
         );
 
         let v2 = Vector::from([-42.0]);
         let scalars2 = [-1.0, 1.0, 0.0];
         assert_eq!(
-            linear_combination(&mut [v2, v2, v2], &scalars2),
+            linear_combination(&[v2, v2, v2], &scalars2),
             Vector::from([0.0])
         );
 
@@ -41,7 +42,7 @@ mod ops_tests {
         let v5 = Vector::from([10.0, 20.0]);
         let scalars3 = [1.0, -10.0, -1.0];
         assert_eq!(
-            linear_combination(&mut [v3, v4, v5], &scalars3),
+            linear_combination(&[v3, v4, v5], &scalars3),
             Vector::from([-62.0, -8.0])
         );
 
@@ -49,7 +50,7 @@ mod ops_tests {
         let v7 = Vector::from([1.0, 3.0, 5.0]);
         let scalars4 = [1.0, -10.0];
         assert_eq!(
-            linear_combination(&mut [v6, v7], &scalars4),
+            linear_combination(&[v6, v7], &scalars4),
             Vector::from([-52.0, 70.0, -119.5])
         );
     }
@@ -75,7 +76,7 @@ mod ops_tests {
         let u = Vector::from([1., 2., 3.] as [f32; 3]);
         let v = Vector::from([4., 5., 6.] as [f32; 3]);
         println!("{:?}", angle_cos(&u, &v));
-        assert!((angle_cos(&u, &v) - 0.974631846).abs() < f32::EPSILON); // 0.974631846
+        assert!((angle_cos(&u, &v) - 0.974_631_85).abs() < f32::EPSILON); // 0.974631846
     }
 
     #[test]
@@ -90,7 +91,7 @@ mod ops_tests {
 
         let u3 = Vector::from([1.0, 1.0]);
         let v3 = Vector::from([1.0, 1.0]);
-        assert_eq!((angle_cos(&u3, &v3) - 1.0) < f64::EPSILON, true);
+        assert!((angle_cos(&u3, &v3) - 1.0) < f64::EPSILON);
 
         let u4 = Vector::from([4.0, 2.0]);
         let v4 = Vector::from([1.0, 1.0]);
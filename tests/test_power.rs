@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod power_tests {
+    use mini_matrix::{LinalgError, Matrix};
+    use num::rational::Ratio;
+
+    fn naive_power<const N: usize>(a: &Matrix<i64, N, N>, exponent: u64) -> Matrix<i64, N, N> {
+        (0..exponent).fold(Matrix::identity(), |power, _| power * *a)
+    }
+
+    #[test]
+    fn test_matches_repeated_multiplication() {
+        let a = Matrix::<i64, 3, 3>::from([[1, -2, 0], [3, 1, 1], [-1, 0, 2]]);
+        for exponent in 0..=12 {
+            assert_eq!(a.pow(exponent), naive_power(&a, exponent));
+            assert_eq!(a.checked_pow(exponent), Ok(naive_power(&a, exponent)));
+        }
+        assert_eq!(Matrix::<i64, 2, 2>::zero().pow(0), Matrix::identity());
+        assert_eq!(Matrix::<i64, 2, 2>::zero().pow(5), Matrix::zero());
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        let fibonacci = Matrix::<u64, 2, 2>::from([[1, 1], [1, 0]]);
+        let (mut previous, mut current) = (0u64, 1u64);
+        for n in 1..=92 {
+            assert_eq!(fibonacci.checked_pow(n).unwrap()[(0, 1)], current);
+            (previous, current) = (current, previous + current);
+        }
+        assert_eq!(fibonacci.checked_pow(93), Err(LinalgError::Overflow));
+        assert_eq!(fibonacci.checked_pow(u64::MAX), Err(LinalgError::Overflow));
+
+        // The powers of a permutation matrix only ever hold zeros and ones, so even the checked
+        // arithmetic cannot overflow, however large the exponent.
+        let swap = Matrix::<u8, 2, 2>::from([[0, 1], [1, 0]]);
+        assert_eq!(swap.checked_pow(u64::MAX), Ok(swap));
+        assert_eq!(swap.pow(1 << 40), Matrix::identity());
+    }
+
+    #[test]
+    fn test_markov_chain() {
+        // A two-state chain whose stationary distribution is (2/3, 1/3).
+        let transition = Matrix::<f64, 2, 2>::from([[0.9, 0.1], [0.2, 0.8]]);
+        let limit = transition.pow(200);
+        for i in 0..2 {
+            assert!((limit[(i, 0)] - 2.0 / 3.0).abs() < 1e-12);
+            assert!((limit[(i, 1)] - 1.0 / 3.0).abs() < 1e-12);
+        }
+
+        // With exact fractions, every power stays exactly stochastic.
+        let exact = Matrix::from([
+            [Ratio::new(9i64, 10), Ratio::new(1, 10)],
+            [Ratio::new(2, 10), Ratio::new(8, 10)],
+        ]);
+        let power = exact.pow(7);
+        for i in 0..2 {
+            assert_eq!(power[(i, 0)] + power[(i, 1)], Ratio::from_integer(1));
+        }
+    }
+
+    #[test]
+    fn test_negative_exponents() {
+        let a = Matrix::<f64, 2, 2>::from([[2.0, 1.0], [1.0, 1.0]]);
+        let inverse = Matrix::from([[1.0, -1.0], [-1.0, 2.0]]);
+        assert_eq!(a.powi(-1), Ok(inverse));
+        assert_eq!(a.powi(-3), Ok(inverse.pow(3)));
+        assert_eq!(a.powi(0), Ok(Matrix::identity()));
+        assert_eq!(a.powi(4), Ok(a.pow(4)));
+
+        let product = {
+            let mut p = a.powi(5).unwrap();
+            p.mul_mat(&a.powi(-5).unwrap())
+        };
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product[(i, j)] - expected).abs() < 1e-10);
+            }
+        }
+
+        let singular = Matrix::<f64, 2, 2>::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.powi(-2), Err(LinalgError::Singular));
+        assert_eq!(singular.powi(0), Ok(Matrix::identity()));
+    }
+}
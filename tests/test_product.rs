@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod product_tests {
+    use mini_matrix::{Matrix, Vector};
+    use num::rational::Ratio;
+
+    #[test]
+    fn test_hadamard() {
+        let a = Matrix::<f64, 2, 3>::from([[1.5, -2.0, 0.0], [4.0, 0.5, -1.0]]);
+        let b = Matrix::from([[2.0, 3.0, 7.0], [-0.25, 4.0, -1.0]]);
+        assert_eq!(a.hadamard(&b), b.hadamard(&a));
+        assert_eq!(a.hadamard(&b).store, [[3.0, -6.0, 0.0], [-1.0, 2.0, 1.0]]);
+
+        let ones = Matrix::from([[1.0; 3]; 2]);
+        assert_eq!(a.hadamard(&ones), a);
+        assert_eq!(a.hadamard(&Matrix::zero()), Matrix::zero());
+    }
+
+    #[test]
+    fn test_elementwise_div() {
+        let a = Matrix::<f64, 2, 2>::from([[1.0, -3.0], [0.0, 1.0]]);
+        let b = Matrix::from([[8.0, 2.0], [0.0, 0.0]]);
+        let quotient = a.elementwise_div(&b);
+        assert_eq!(quotient[(0, 0)], 0.125);
+        assert_eq!(quotient[(0, 1)], -1.5);
+        assert!(quotient[(1, 0)].is_nan());
+        assert_eq!(quotient[(1, 1)], f64::INFINITY);
+
+        // Division undoes the Hadamard product exactly over the rationals.
+        let r = |n: i64, d: i64| Ratio::new(n, d);
+        let c = Matrix::from([[r(2, 3), r(-5, 7)], [r(1, 1), r(9, 4)]]);
+        let d = Matrix::from([[r(3, 2), r(1, 5)], [r(-8, 1), r(2, 9)]]);
+        assert_eq!(c.hadamard(&d).elementwise_div(&d), c);
+
+        let integers =
+            Matrix::from([[7, -9], [10, 3]]).elementwise_div(&Matrix::from([[2, 4], [5, 3]]));
+        assert_eq!(integers.store, [[3, -2], [2, 1]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_integer_division_by_zero() {
+        let _ = Matrix::from([[1, 2]]).elementwise_div(&Matrix::from([[1, 0]]));
+    }
+
+    #[test]
+    fn test_kronecker_shapes() {
+        let a = Matrix::from([[1, -1, 2]]);
+        let b = Matrix::from([[3], [4]]);
+        let ab: Matrix<i32, 2, 3> = a.kronecker(&b);
+        assert_eq!(ab.store, [[3, -3, 6], [4, -4, 8]]);
+        let ba: Matrix<i32, 2, 3> = b.kronecker(&a);
+        assert_eq!(ba.store, [[3, -3, 6], [4, -4, 8]]);
+
+        // I ⊗ B is block diagonal.
+        let c = Matrix::from([[1, 2], [3, 4]]);
+        let blocks: Matrix<i32, 4, 4> = Matrix::<i32, 2, 2>::identity().kronecker(&c);
+        assert_eq!(
+            blocks.store,
+            [[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 2], [0, 0, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn test_kronecker_mixed_product() {
+        // (A ⊗ B)(C ⊗ D) = (AC) ⊗ (BD)
+        let mut a = Matrix::<i64, 2, 2>::from([[1, 2], [0, -1]]);
+        let mut b = Matrix::<i64, 2, 3>::from([[2, 0, 1], [1, 3, -2]]);
+        let c = Matrix::<i64, 2, 2>::from([[4, -1], [2, 5]]);
+        let d = Matrix::<i64, 3, 2>::from([[1, 0], [-1, 2], [3, 1]]);
+
+        let mut ab: Matrix<i64, 4, 6> = a.kronecker(&b);
+        let cd: Matrix<i64, 6, 4> = c.kronecker(&d);
+        let left = ab.mul_mat(&cd);
+        let right: Matrix<i64, 4, 4> = a.mul_mat(&c).kronecker(&b.mul_mat(&d));
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn test_outer() {
+        let u = Vector::<f64, 3>::from([1.0, -2.0, 0.5]);
+        let v = Vector::from([3.0, 4.0]);
+        let mut uv = u.outer(&v);
+        assert_eq!(uv.store, [[3.0, 4.0], [-6.0, -8.0], [1.5, 2.0]]);
+        assert_eq!(uv.rank(), 1);
+
+        // (u vᵀ) w = u (v · w)
+        let w = Vector::from([0.5, -1.0]);
+        assert_eq!(uv.mul_vec(&w), u * v.dot(&w));
+
+        let mut transposed = uv;
+        assert_eq!(transposed.transpose(), v.outer(&u));
+        assert_eq!(u.outer(&Vector::<f64, 0>::from([])).store.len(), 3);
+    }
+}
@@ -0,0 +1,93 @@
+mod common;
+
+#[cfg(test)]
+mod qr_tests {
+    use crate::common::assert_close;
+    use mini_matrix::{LinalgError, Matrix, Vector};
+
+    #[test]
+    fn test_qr_full() {
+        let a: Matrix<f64, 4, 3> = Matrix::from([
+            [12., -51., 4.],
+            [6., 167., -68.],
+            [-4., 24., -41.],
+            [1., 1., 1.],
+        ]);
+        let qr = a.qr();
+        let q = qr.q();
+        let r = qr.r();
+
+        assert_close(&(q * r), &a, 1e-12);
+
+        let mut q_t = q;
+        assert_close(&(q_t.transpose() * q), &Matrix::identity(), 1e-12);
+
+        for i in 0..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(r[(i, j)], 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_thin() {
+        let a: Matrix<f64, 4, 2> = Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]);
+        let qr = a.qr();
+        let q = qr.thin_q();
+        let r = qr.thin_r();
+
+        assert_close(&(q * r), &a, 1e-12);
+
+        let mut q_t = q;
+        assert_close(&(q_t.transpose() * q), &Matrix::identity(), 1e-12);
+        assert_eq!(r[(1, 0)], 0.);
+
+        // The thin factors are the leading blocks of the full ones.
+        let full_q = qr.q();
+        let full_r = qr.r();
+        for i in 0..4 {
+            for j in 0..2 {
+                assert_eq!(q[(i, j)], full_q[(i, j)]);
+            }
+        }
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_eq!(r[(i, j)], full_r[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_square() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]);
+        let qr = a.qr();
+        assert_close(&(qr.q() * qr.r()), &a, 1e-12);
+
+        // |det(A)| is the product of the diagonal of R.
+        let r = qr.r();
+        assert!(((r[(0, 0)] * r[(1, 1)] * r[(2, 2)]).abs() - 4.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_qr_rank_deficient() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[1., 2., 0.], [2., 4., 0.], [3., 6., 0.]]);
+        let qr = a.qr();
+        assert_close(&(qr.q() * qr.r()), &a, 1e-12);
+        assert_eq!(
+            qr.solve(&Vector::from([1., 2., 3.])),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_qr_least_squares() {
+        // Points scattered around y = 2t + 1.
+        let a: Matrix<f64, 5, 2> = Matrix::from([[1., 0.], [1., 1.], [1., 2.], [1., 3.], [1., 4.]]);
+        let b = Vector::from([1.1, 2.9, 5.2, 6.8, 9.0]);
+        let x = a.qr().solve(&b).unwrap();
+
+        // Normal equations: [5 10; 10 30] x = [25, 69.7].
+        assert!((x[0] - 1.06).abs() < 1e-12);
+        assert!((x[1] - 1.97).abs() < 1e-12);
+    }
+}
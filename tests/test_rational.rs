@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod rational_tests {
+    use mini_matrix::{LinalgError, Matrix};
+    use num::rational::Ratio;
+    use num::{BigInt, BigRational, One, Zero};
+
+    fn r(numerator: i64, denominator: i64) -> Ratio<i64> {
+        Ratio::new(numerator, denominator)
+    }
+
+    fn big(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    fn hilbert<const N: usize>() -> Matrix<BigRational, N, N> {
+        let mut h = Matrix::zero();
+        for i in 0..N {
+            for j in 0..N {
+                h[(i, j)] = big(1, (i + j + 1) as i64);
+            }
+        }
+        h
+    }
+
+    #[test]
+    fn test_row_echelon_is_exact() {
+        let a = Matrix::from([
+            [r(1, 1), r(2, 1), r(3, 1), r(4, 1)],
+            [r(5, 1), r(6, 1), r(7, 1), r(8, 1)],
+            [r(9, 1), r(10, 1), r(11, 1), r(12, 1)],
+        ]);
+        assert_eq!(
+            a.row_echelon().store,
+            [
+                [r(1, 1), r(0, 1), r(-1, 1), r(-2, 1)],
+                [r(0, 1), r(1, 1), r(2, 1), r(3, 1)],
+                [r(0, 1), r(0, 1), r(0, 1), r(0, 1)],
+            ]
+        );
+
+        let b = Matrix::from([[r(8, 1), r(5, 1), r(-2, 1)], [r(4, 1), r(7, 1), r(20, 1)]]);
+        assert_eq!(
+            b.row_echelon().store,
+            [[r(1, 1), r(0, 1), r(-19, 6)], [r(0, 1), r(1, 1), r(14, 3)]]
+        );
+    }
+
+    #[test]
+    fn test_rank_is_exact() {
+        // In binary floating point 0.1, 0.2, ... are inexact, and the dependent third row does not
+        // cancel exactly; as fractions it does.
+        let a = Matrix::from([
+            [r(1, 10), r(2, 10), r(3, 10)],
+            [r(4, 10), r(5, 10), r(6, 10)],
+            [r(7, 10), r(8, 10), r(9, 10)],
+        ]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.determinant(), r(0, 1));
+        assert_eq!(a.inverse_exact(), Err(LinalgError::Singular));
+        assert_eq!(Matrix::<Ratio<i64>, 3, 3>::zero().rank(), 0);
+    }
+
+    #[test]
+    fn test_determinant_and_inverse() {
+        let a = Matrix::from([
+            [r(2, 1), r(-1, 2), r(0, 1)],
+            [r(1, 3), r(1, 1), r(1, 1)],
+            [r(0, 1), r(2, 1), r(1, 4)],
+        ]);
+        // 2 * (1/4 - 2) + 1/2 * (1/12 - 0) = -7/2 + 1/24
+        assert_eq!(a.determinant(), r(-83, 24));
+
+        let inv = a.inverse_exact().unwrap();
+        let mut identity = Matrix::<Ratio<i64>, 3, 3>::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    identity[(i, j)] += a[(i, k)] * inv[(k, j)];
+                }
+            }
+        }
+        assert_eq!(identity, Matrix::identity());
+        assert_eq!(inv.determinant(), r(-24, 83));
+    }
+
+    #[test]
+    fn test_pivoting_on_zero() {
+        let a = Matrix::from([[r(0, 1), r(1, 1)], [r(1, 1), r(0, 1)]]);
+        assert_eq!(a.determinant(), r(-1, 1));
+        assert_eq!(a.inverse_exact().unwrap(), a);
+        assert_eq!(a.rank(), 2);
+    }
+
+    #[test]
+    fn test_big_rational_hilbert() {
+        let h = hilbert::<6>();
+        assert_eq!(h.determinant(), big(1, 186313420339200000));
+        assert_eq!(h.rank(), 6);
+        let inv = h.inverse_exact().unwrap();
+        assert_eq!(inv.inverse_exact().unwrap(), h);
+        assert!(inv.store.iter().flatten().all(|x| x.is_integer()));
+
+        let h4 = hilbert::<4>().inverse_exact().unwrap();
+        let expected = [
+            [16, -120, 240, -140],
+            [-120, 1200, -2700, 1680],
+            [240, -2700, 6480, -4200],
+            [-140, 1680, -4200, 2800],
+        ];
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(h4[(i, j)], big(expected[i][j], 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_big_rational_row_echelon() {
+        let a = Matrix::from([
+            [big(1, 2), big(1, 3), big(1, 1)],
+            [big(1, 1), big(2, 3), big(2, 1)],
+        ]);
+        let rref = a.row_echelon();
+        assert_eq!(
+            rref.store,
+            [
+                [big(1, 1), big(2, 3), big(2, 1)],
+                [
+                    BigRational::zero(),
+                    BigRational::zero(),
+                    BigRational::zero()
+                ],
+            ]
+        );
+        assert_eq!(a.rank(), 1);
+        assert!(Matrix::<BigRational, 2, 2>::identity()[(1, 1)].is_one());
+    }
+
+    #[test]
+    fn test_display() {
+        let a = Matrix::from([[r(73, 6), r(-1, 2)], [r(0, 1), r(5, 1)]]);
+        assert_eq!(format!("{}", a), "// [73/6, -1/2]\n// [0, 5]\n");
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod sparse_tests {
+    use mini_matrix::{CooMatrix, CscMatrix, CsrMatrix, DMatrix, DVector, LinalgError, Matrix};
+
+    fn sample() -> Matrix<i32, 3, 4> {
+        Matrix::from([[1, 0, 0, 2], [0, 0, 0, 0], [3, 0, 4, 0]])
+    }
+
+    #[test]
+    fn test_coo_assembly() {
+        let mut coo = CooMatrix::new(2, 3);
+        coo.push(1, 2, 5).unwrap();
+        coo.push(0, 0, 1).unwrap();
+        coo.push(1, 2, -2).unwrap();
+        coo.push(0, 1, 4).unwrap();
+        assert_eq!(coo.size(), (2, 3));
+        assert_eq!(coo.nnz(), 4);
+        assert_eq!(
+            coo.push(2, 0, 1),
+            Err(LinalgError::IndexOutOfBounds {
+                index: (2, 0),
+                shape: (2, 3)
+            })
+        );
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(csr.row_offsets(), &[0, 2, 3]);
+        assert_eq!(csr.col_indices(), &[0, 1, 2]);
+        assert_eq!(csr.values(), &[1, 4, 3]);
+
+        let csc = coo.to_csc();
+        assert_eq!(csc.col_offsets(), &[0, 1, 2, 3]);
+        assert_eq!(csc.row_indices(), &[0, 0, 1]);
+        assert_eq!(csc.values(), &[1, 4, 3]);
+
+        assert_eq!(
+            coo.to_dense(),
+            DMatrix::from_vecs(vec![vec![1, 4, 0], vec![0, 0, 3]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_empty_rows_and_columns() {
+        let csr = CsrMatrix::from(sample());
+        assert_eq!(csr.row_offsets(), &[0, 2, 2, 4]);
+        assert_eq!(csr.get(1, 3), 0);
+        assert_eq!(csr.get(2, 2), 4);
+
+        let csc = CscMatrix::from(sample());
+        assert_eq!(csc.col_offsets(), &[0, 2, 2, 3, 4]);
+        assert_eq!(csc.get(1, 1), 0);
+        assert_eq!(csc.get(0, 3), 2);
+
+        let empty = CooMatrix::<f64>::new(0, 0).to_csr();
+        assert_eq!(empty.size(), (0, 0));
+        assert_eq!(empty.nnz(), 0);
+        assert_eq!(empty.row_offsets(), &[0]);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let csr = CsrMatrix::from(sample());
+        let csc = CscMatrix::from(sample());
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(csr.to_dense(), DMatrix::from(sample()));
+        assert_eq!(csc.to_dense(), DMatrix::from(sample()));
+        assert_eq!(Matrix::<i32, 3, 4>::try_from(csr.clone()), Ok(sample()));
+        assert_eq!(Matrix::<i32, 3, 4>::try_from(csc), Ok(sample()));
+        assert_eq!(
+            Matrix::<i32, 4, 3>::try_from(csr),
+            Err(LinalgError::DimensionMismatch {
+                expected: (4, 3),
+                found: (3, 4)
+            })
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let mut dense = sample();
+        let transposed = dense.transpose();
+        assert_eq!(
+            Matrix::try_from(CsrMatrix::from(sample()).transpose()),
+            Ok(transposed)
+        );
+        assert_eq!(
+            Matrix::try_from(CscMatrix::from(sample()).transpose()),
+            Ok(transposed)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_out_of_bounds() {
+        let csr = CsrMatrix::from(sample());
+        let _ = csr.get(3, 0);
+    }
+
+    #[test]
+    fn test_mul_vec() {
+        let x = DVector::from(vec![1, -1, 2, 3]);
+        let expected = DMatrix::from(sample()).mul_vec(&x).unwrap();
+        assert_eq!(expected.store, vec![7, 0, 11]);
+        assert_eq!(CsrMatrix::from(sample()).mul_vec(&x), Ok(expected.clone()));
+        assert_eq!(CscMatrix::from(sample()).mul_vec(&x), Ok(expected));
+
+        let short = DVector::from(vec![1, 2, 3]);
+        let mismatch = Err(LinalgError::DimensionMismatch {
+            expected: (4, 1),
+            found: (3, 1),
+        });
+        assert_eq!(CsrMatrix::from(sample()).mul_vec(&short), mismatch);
+        assert_eq!(CscMatrix::from(sample()).mul_vec(&short), mismatch);
+    }
+
+    #[test]
+    fn test_mul_dense() {
+        let b = DMatrix::from_vecs(vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]]).unwrap();
+        let expected = DMatrix::from(sample()).mul_mat(&b).unwrap();
+        assert_eq!(
+            CsrMatrix::from(sample()).mul_dense(&b),
+            Ok(expected.clone())
+        );
+        assert_eq!(CscMatrix::from(sample()).mul_dense(&b), Ok(expected));
+
+        let wrong = DMatrix::<i32>::zero(3, 2);
+        let mismatch = Err(LinalgError::DimensionMismatch {
+            expected: (4, 2),
+            found: (3, 2),
+        });
+        assert_eq!(CsrMatrix::from(sample()).mul_dense(&wrong), mismatch);
+        assert_eq!(CscMatrix::from(sample()).mul_dense(&wrong), mismatch);
+    }
+
+    #[test]
+    fn test_large_tridiagonal() {
+        // Second-difference operator on a 1000-point grid, assembled element by element.
+        let n = 1000;
+        let mut coo = CooMatrix::new(n, n);
+        for e in 0..n - 1 {
+            coo.push(e, e, 1.0).unwrap();
+            coo.push(e, e + 1, -1.0).unwrap();
+            coo.push(e + 1, e, -1.0).unwrap();
+            coo.push(e + 1, e + 1, 1.0).unwrap();
+        }
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 3 * n - 2);
+        assert_eq!(csr.get(0, 0), 1.0);
+        assert_eq!(csr.get(500, 500), 2.0);
+        assert_eq!(csr.get(500, 501), -1.0);
+        assert_eq!(csr.get(500, 502), 0.0);
+        assert_eq!(csr.transpose(), csr);
+
+        // The operator annihilates constants and maps a linear ramp to boundary terms only.
+        let ones = DVector::from(vec![1.0; n]);
+        assert!(csr.mul_vec(&ones).unwrap().iter().all(|&y| y == 0.0));
+        let ramp = DVector::from((0..n).map(|i| i as f64).collect::<Vec<_>>());
+        let y = coo.to_csc().mul_vec(&ramp).unwrap();
+        assert_eq!(y[0], -1.0);
+        assert_eq!(y[n - 1], 1.0);
+        assert!(y.store[1..n - 1].iter().all(|&v| v == 0.0));
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod stationary_tests {
+    use mini_matrix::{LinalgError, Matrix, Vector};
+
+    fn assert_close<const N: usize>(a: &Vector<f64, N>, b: &Vector<f64, N>, tolerance: f64) {
+        for i in 0..N {
+            assert!(
+                (a[i] - b[i]).abs() < tolerance,
+                "{} != {} at {}",
+                a[i],
+                b[i],
+                i
+            );
+        }
+    }
+
+    fn dominant() -> Matrix<f64, 4, 4> {
+        Matrix::from([
+            [10.0, -1.0, 2.0, 0.0],
+            [-1.0, 11.0, -1.0, 3.0],
+            [2.0, -1.0, 10.0, -1.0],
+            [0.0, 3.0, -1.0, 8.0],
+        ])
+    }
+
+    #[test]
+    fn test_methods_agree_with_elimination() {
+        let a = dominant();
+        let b = Vector::from([6.0, 25.0, -11.0, 15.0]);
+        let exact = a.solve(&b).unwrap();
+        assert_close(&exact, &Vector::from([1.0, 2.0, -1.0, 1.0]), 1e-12);
+
+        for result in [
+            a.jacobi(&b, 1e-12, 200).unwrap(),
+            a.gauss_seidel(&b, 1e-12, 200).unwrap(),
+            a.sor(&b, 1.05, 1e-12, 200).unwrap(),
+        ] {
+            assert!(result.diagonally_dominant());
+            assert!(result.converged());
+            assert_eq!(result.residual_history().len(), result.iterations() + 1);
+            assert!(result.residual_norm() <= 1e-12 * b.norm());
+            assert_close(&result.solution(), &exact, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_gauss_seidel_beats_jacobi() {
+        let a = dominant();
+        let b = Vector::from([6.0, 25.0, -11.0, 15.0]);
+        let jacobi = a.jacobi(&b, 1e-10, 200).unwrap();
+        let gauss_seidel = a.gauss_seidel(&b, 1e-10, 200).unwrap();
+        assert!(gauss_seidel.iterations() < jacobi.iterations());
+
+        // On this diagonally dominant matrix the Gauss–Seidel residual decreases at every step.
+        let history = gauss_seidel.residual_history();
+        assert!(history.windows(2).all(|pair| pair[1] < pair[0]));
+    }
+
+    #[test]
+    fn test_over_relaxation() {
+        // The 1D Laplacian converges slowly under Gauss–Seidel; SOR with a near-optimal factor
+        // ω = 2 / (1 + sin(π / (N + 1))) is much faster.
+        let mut a = Matrix::<f64, 8, 8>::zero();
+        for i in 0..8 {
+            a[(i, i)] = 2.0;
+            if i > 0 {
+                a[(i, i - 1)] = -1.0;
+                a[(i - 1, i)] = -1.0;
+            }
+        }
+        assert!(!a.is_diagonally_dominant());
+        let b = Vector::from([1.0; 8]);
+        let omega = 2.0 / (1.0 + (std::f64::consts::PI / 9.0).sin());
+        let gauss_seidel = a.gauss_seidel(&b, 1e-10, 1000).unwrap();
+        let sor = a.sor(&b, omega, 1e-10, 1000).unwrap();
+        assert!(gauss_seidel.converged());
+        assert!(sor.converged());
+        assert!(!sor.diagonally_dominant());
+        assert!(2 * sor.iterations() < gauss_seidel.iterations());
+        assert_close(&sor.solution(), &a.solve(&b).unwrap(), 1e-8);
+    }
+
+    #[test]
+    fn test_divergence_is_reported() {
+        let a = Matrix::<f64, 2, 2>::from([[1.0, 3.0], [2.0, 1.0]]);
+        assert!(!a.is_diagonally_dominant());
+        let b = Vector::from([4.0, 3.0]);
+        let result = a.jacobi(&b, 1e-10, 50).unwrap();
+        assert!(!result.diagonally_dominant());
+        assert!(!result.converged());
+        assert_eq!(result.iterations(), 50);
+        assert!(result.residual_norm() > result.residual_history()[0]);
+    }
+
+    #[test]
+    fn test_zero_diagonal() {
+        let a = Matrix::<f64, 2, 2>::from([[0.0, 1.0], [1.0, 0.0]]);
+        let b = Vector::from([1.0, 1.0]);
+        assert_eq!(a.jacobi(&b, 1e-10, 10), Err(LinalgError::Singular));
+        assert_eq!(a.gauss_seidel(&b, 1e-10, 10), Err(LinalgError::Singular));
+        assert_eq!(a.sor(&b, 1.5, 1e-10, 10), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_sor_rejects_bad_relaxation() {
+        let a = dominant();
+        let b = Vector::from([1.0; 4]);
+        for omega in [0.0, 2.0, -0.5, 3.0] {
+            assert_eq!(
+                a.sor(&b, omega, 1e-10, 10),
+                Err(LinalgError::InvalidRelaxation { omega })
+            );
+        }
+        assert!(a.sor(&b, f64::NAN, 1e-10, 10).is_err());
+        assert_eq!(
+            LinalgError::InvalidRelaxation { omega: 2.5 }.to_string(),
+            "relaxation factor 2.5 is outside (0, 2)"
+        );
+    }
+
+    #[test]
+    fn test_zero_rhs() {
+        let a = dominant();
+        let result = a.gauss_seidel(&Vector::zero(), 1e-10, 10).unwrap();
+        assert!(result.converged());
+        assert_eq!(result.iterations(), 0);
+        assert_eq!(result.solution(), Vector::zero());
+    }
+}
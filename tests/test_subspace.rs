@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod subspace_tests {
+    use mini_matrix::{linear_combination, Matrix, Vector};
+
+    fn assert_negligible<const N: usize>(x: &Vector<f64, N>) {
+        assert!(
+            x.iter().all(|v| v.abs() < 1e-12),
+            "{:?} is not zero",
+            x.store
+        );
+    }
+
+    /// A rank-2 `3 x 4` matrix: the third row is the sum of the first two.
+    fn deficient() -> Matrix<f64, 3, 4> {
+        Matrix::from([
+            [1.0, 2.0, 0.0, -1.0],
+            [2.0, 4.0, 1.0, 3.0],
+            [3.0, 6.0, 1.0, 2.0],
+        ])
+    }
+
+    #[test]
+    fn test_null_space_is_annihilated() {
+        let a = deficient();
+        let null = a.null_space();
+        assert_eq!(null.len(), 4 - a.echelon_rank());
+        for x in &null {
+            assert_negligible(&(a * *x));
+        }
+        // Each basis vector has a one in its own free column and zero in the others.
+        assert_eq!([null[0][1], null[0][3]], [1.0, 0.0]);
+        assert_eq!([null[1][1], null[1][3]], [0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_left_null_space_is_annihilated() {
+        let mut a = deficient();
+        let left = a.left_null_space();
+        assert_eq!(left.len(), 3 - a.echelon_rank());
+        for y in &left {
+            assert_negligible(&(a.transpose() * *y));
+        }
+        // The left null space is orthogonal to the column space.
+        for c in &a.column_space() {
+            for y in &left {
+                assert!(c.dot(y).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_column_and_row_space() {
+        let a = deficient();
+        let columns = a.column_space();
+        let rows = a.row_space();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(columns[0], Vector::from([1.0, 2.0, 3.0]));
+        assert_eq!(columns[1], Vector::from([0.0, 1.0, 1.0]));
+
+        // Every row of A is a combination of the row basis, with the coefficients read off the
+        // pivot columns, and the row space is orthogonal to the null space.
+        let pivots = a.rref().pivot_columns().to_vec();
+        for i in 0..3 {
+            let row = Vector::from(a.store[i]);
+            let coefficients: Vec<f64> = pivots.iter().map(|&j| row[j]).collect();
+            let combination = linear_combination(&rows, &coefficients);
+            assert_negligible(&(row - combination));
+        }
+        for r in &rows {
+            for x in &a.null_space() {
+                assert!(r.dot(x).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_rank_and_zero() {
+        let a = Matrix::<f64, 2, 2>::from([[2.0, 1.0], [1.0, 3.0]]);
+        assert!(a.null_space().is_empty());
+        assert!(a.left_null_space().is_empty());
+        assert_eq!(a.column_space().len(), 2);
+        assert_eq!(
+            a.row_space(),
+            vec![Vector::from([1.0, 0.0]), Vector::from([0.0, 1.0])]
+        );
+
+        let zero = Matrix::<f64, 2, 3>::zero();
+        assert!(zero.column_space().is_empty());
+        assert!(zero.row_space().is_empty());
+        assert_eq!(
+            zero.null_space(),
+            vec![
+                Vector::from([1.0, 0.0, 0.0]),
+                Vector::from([0.0, 1.0, 0.0]),
+                Vector::from([0.0, 0.0, 1.0]),
+            ]
+        );
+        assert_eq!(zero.left_null_space().len(), 2);
+    }
+
+    #[test]
+    fn test_rounding_noise() {
+        // In floating point the third row is only approximately the sum of the others; the
+        // tolerance of `rref` still finds the one-dimensional null space.
+        let mut a = Matrix::<f64, 3, 3>::from([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.5, 0.7, 0.9]]);
+        let null = a.null_space();
+        assert_eq!(null.len(), 1);
+        assert_negligible(&(a * null[0]));
+        assert_eq!(a.left_null_space().len(), 1);
+        let left = a.left_null_space();
+        assert_negligible(&(a.transpose() * left[0]));
+    }
+}
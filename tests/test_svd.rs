@@ -0,0 +1,86 @@
+mod common;
+
+#[cfg(test)]
+mod svd_tests {
+    use crate::common::assert_close;
+    use mini_matrix::{LinalgError, Matrix};
+
+    fn assert_decomposition<const M: usize, const N: usize>(a: &Matrix<f64, M, N>) {
+        let svd = a.svd().unwrap();
+        let (mut u, sigma, mut vt) = (svd.u(), svd.sigma(), svd.vt());
+        assert_close(&(u * sigma * vt), a, 1e-12);
+        assert_close(&(u.transpose() * u), &Matrix::identity(), 1e-12);
+        assert_close(&(vt * vt.transpose()), &Matrix::identity(), 1e-12);
+
+        let values = svd.singular_values();
+        assert_eq!(values.len(), M.min(N));
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!(values.iter().all(|&value| value >= 0.0));
+    }
+
+    #[test]
+    fn test_svd_square() {
+        let a: Matrix<f64, 3, 3> = Matrix::from([[4., 11., 14.], [8., 7., -2.], [1., -3., 5.]]);
+        assert_decomposition(&a);
+    }
+
+    #[test]
+    fn test_svd_tall() {
+        let a: Matrix<f64, 4, 2> = Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]);
+        assert_decomposition(&a);
+    }
+
+    #[test]
+    fn test_svd_wide() {
+        let a: Matrix<f64, 2, 3> = Matrix::from([[3., 2., 2.], [2., 3., -2.]]);
+        assert_decomposition(&a);
+
+        let values = a.singular_values().unwrap();
+        assert!((values[0] - 5.0).abs() < 1e-12);
+        assert!((values[1] - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let a: Matrix<f64, 4, 3> =
+            Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 0., 1.], [0., 1., 1.]]);
+        assert_decomposition(&a);
+        assert_eq!(a.rank_with_tolerance(1e-10), Ok(2));
+        let condition = a.condition_number().unwrap();
+        assert!(condition.is_infinite() || condition > 1e14);
+
+        let zero: Matrix<f64, 3, 2> = Matrix::zero();
+        assert_decomposition(&zero);
+        assert_eq!(zero.rank_with_tolerance(1e-10), Ok(0));
+        assert_eq!(zero.spectral_norm(), Ok(0.0));
+    }
+
+    #[test]
+    fn test_spectral_norm_and_condition_number() {
+        let a: Matrix<f64, 2, 2> = Matrix::from([[1., 1.], [0., 1.]]);
+        let golden = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        assert!((a.spectral_norm().unwrap() - golden).abs() < 1e-12);
+        assert!((a.condition_number().unwrap() - golden * golden).abs() < 1e-12);
+
+        let identity: Matrix<f64, 4, 4> = Matrix::identity();
+        assert_eq!(identity.singular_values(), Ok(vec![1.0; 4]));
+        assert_eq!(identity.condition_number(), Ok(1.0));
+    }
+
+    #[test]
+    fn test_svd_nan_does_not_panic() {
+        // A single column needs no rotation, so the NaN only reaches the sort and the completion
+        // of the left singular vectors.
+        let a: Matrix<f64, 3, 1> = Matrix::from([[f64::NAN], [1.], [2.]]);
+        let svd = a.svd().unwrap();
+        assert!(svd.singular_values()[0].is_nan());
+    }
+
+    #[test]
+    fn test_svd_reports_no_convergence() {
+        // The NaN makes every rotation threshold NaN, so the sweeps never settle.
+        let a: Matrix<f64, 3, 2> = Matrix::from([[f64::NAN, 1.], [0., 1.], [2., 0.]]);
+        assert_eq!(a.svd(), Err(LinalgError::NoConvergence));
+        assert_eq!(a.spectral_norm(), Err(LinalgError::NoConvergence));
+    }
+}